@@ -1,17 +1,16 @@
-
 use ark_ff::PrimeField;
 use sha3::{Digest, Keccak256};
 use std::marker::PhantomData;
 
 // This struct represents a cryptographic transcript that accumulates data and hashes it.
-struct Transcript<F: PrimeField, T: HashTrait> {
+pub struct Transcript<F: PrimeField, T: HashTrait> {
     hasher: T,
     f_element: PhantomData<F>,
 }
 
 // Defines how to interact with the transcript.
 impl<F: PrimeField, T: HashTrait> Transcript<F, T> {
-    fn init(hash_function: T) -> Self {
+    pub fn init(hash_function: T) -> Self {
         Transcript {
             hasher: hash_function,
             f_element: PhantomData,
@@ -19,19 +18,19 @@ impl<F: PrimeField, T: HashTrait> Transcript<F, T> {
     }
 
     // Feeds data into the hasher
-    fn append(&mut self, data: &[u8]) {
+    pub fn append(&mut self, data: &[u8]) {
         self.hasher.absorb(data);
     }
 
     // Computes the final hash and ensures consistency across multiple calls
-    fn hash(&mut self) -> F {
+    pub fn hash(&mut self) -> F {
         let hash = self.hasher.squeeze();
         F::from_be_bytes_mod_order(&hash)
     }
 }
 
 // Trait defining hash functions
-trait HashTrait {
+pub trait HashTrait {
     fn absorb(&mut self, data: &[u8]);
     fn squeeze(&mut self) -> Vec<u8>;
 }
@@ -70,7 +69,3 @@ mod tests {
         assert_eq!(hash, hash3);
     }
 }
-
-// fn main() {
-//     println!("Hello, world!");
-// }
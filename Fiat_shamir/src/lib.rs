@@ -0,0 +1 @@
+pub mod fiat_shamir;
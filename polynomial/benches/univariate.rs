@@ -0,0 +1,47 @@
+use ark_bn254::Fr;
+use ark_std::{test_rng, UniformRand};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use polynomial::univariate::DensePolynomial;
+
+fn random_values(n: usize) -> Vec<Fr> {
+    let mut rng = test_rng();
+    (0..n).map(|_| Fr::rand(&mut rng)).collect()
+}
+
+fn bench_multi_evaluate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("multi_evaluate");
+    for n in [256, 1024, 4096] {
+        let poly = DensePolynomial::new(random_values(n));
+        let points = random_values(n);
+
+        group.bench_with_input(BenchmarkId::new("naive", n), &n, |b, _| {
+            b.iter(|| points.iter().map(|&x| poly.evaluate(x)).collect::<Vec<_>>())
+        });
+        group.bench_with_input(BenchmarkId::new("subproduct_tree", n), &n, |b, _| {
+            b.iter(|| poly.multi_evaluate(&points))
+        });
+    }
+    group.finish();
+}
+
+fn bench_interpolate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("interpolate");
+    group.sample_size(10);
+    for n in [64, 256, 1024] {
+        let points: Vec<(Fr, Fr)> = random_values(n)
+            .into_iter()
+            .zip(random_values(2 * n).into_iter().skip(n))
+            .collect();
+
+        group.bench_with_input(BenchmarkId::new("naive", n), &n, |b, _| {
+            b.iter(|| DensePolynomial::interpolate(&points))
+        });
+        group.bench_with_input(BenchmarkId::new("subproduct_tree", n), &n, |b, _| {
+            b.iter(|| DensePolynomial::interpolate_fast(&points))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_multi_evaluate, bench_interpolate);
+criterion_main!(benches);
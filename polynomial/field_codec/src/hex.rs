@@ -0,0 +1,52 @@
+//! The human-readable field element encoding shared by the serde impls of
+//! every polynomial type: the canonical integer in big-endian hex.
+
+use ark_ff::{BigInteger, PrimeField};
+
+pub fn field_to_hex<F: PrimeField>(x: &F) -> String {
+    hex::encode(x.into_bigint().to_bytes_be())
+}
+
+/// Inverse of `field_to_hex`. Leading zero bytes may be dropped, but values
+/// at or above the modulus are rejected rather than reduced.
+pub fn hex_to_field<F: PrimeField>(s: &str) -> Result<F, String> {
+    let bytes = hex::decode(s).map_err(|e| e.to_string())?;
+
+    let mut bigint = F::BigInt::default();
+    let limbs = bigint.as_mut();
+    if bytes.len() > limbs.len() * 8 {
+        return Err(format!("field element {s} is too long"));
+    }
+    for (i, byte) in bytes.iter().rev().enumerate() {
+        limbs[i / 8] |= (*byte as u64) << (8 * (i % 8));
+    }
+
+    F::from_bigint(bigint).ok_or_else(|| format!("field element {s} is not canonical"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+    use ark_std::{test_rng, UniformRand};
+
+    #[test]
+    fn test_round_trip() {
+        let mut rng = test_rng();
+        for x in [Fr::from(0), -Fr::from(1), Fr::rand(&mut rng)] {
+            let s = field_to_hex(&x);
+            assert_eq!(s.len(), 64);
+            assert_eq!(hex_to_field::<Fr>(&s), Ok(x));
+        }
+        assert_eq!(hex_to_field::<Fr>("0102"), Ok(Fr::from(0x0102)));
+    }
+
+    #[test]
+    fn test_rejects_bad_input() {
+        // the BN254 scalar field modulus itself is not a canonical element
+        let modulus = "30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001";
+        assert!(hex_to_field::<Fr>(modulus).is_err());
+        assert!(hex_to_field::<Fr>(&"01".repeat(33)).is_err());
+        assert!(hex_to_field::<Fr>("zz").is_err());
+    }
+}
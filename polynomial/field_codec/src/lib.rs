@@ -0,0 +1,10 @@
+//! Encoding details shared by the serialized forms of the univariate and
+//! multilinear polynomial crates.
+
+#[cfg(feature = "hex")]
+pub mod hex;
+
+/// Leading byte of every serialized polynomial, dense univariate or
+/// multilinear table. Bumped whenever either layout changes so old fixtures
+/// are rejected instead of misread.
+pub const SERIALIZATION_VERSION: u8 = 1;
@@ -0,0 +1,70 @@
+use ark_bn254::Fr;
+use ark_std::{test_rng, UniformRand};
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use multilinear::multilinear::MultilinearEvalForm;
+
+fn random_mle(num_vars: usize) -> MultilinearEvalForm<Fr> {
+    let mut rng = test_rng();
+    let values: Vec<Fr> = (0..1 << num_vars).map(|_| Fr::rand(&mut rng)).collect();
+    MultilinearEvalForm::new(&values)
+}
+
+// One full sumcheck-style pass: bind variable 0 until the table is a constant
+fn bench_fold_all_variables(c: &mut Criterion) {
+    let mut group = c.benchmark_group("fold_all_variables");
+    group.sample_size(10);
+    for num_vars in [16, 20] {
+        let mle = random_mle(num_vars);
+        let r = Fr::from(7);
+
+        group.bench_with_input(
+            BenchmarkId::new("partial_evaluate", num_vars),
+            &num_vars,
+            |b, _| {
+                b.iter_batched(
+                    || mle.clone(),
+                    |mut table| {
+                        for _ in 0..num_vars {
+                            table = MultilinearEvalForm::new(&table.partial_evaluate(0, r));
+                        }
+                        table
+                    },
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+        group.bench_with_input(BenchmarkId::new("in_place", num_vars), &num_vars, |b, _| {
+            b.iter_batched(
+                || mle.clone(),
+                |mut table| {
+                    for _ in 0..num_vars {
+                        table.fix_variable_in_place(r);
+                    }
+                    table
+                },
+                BatchSize::LargeInput,
+            )
+        });
+        #[cfg(feature = "parallel")]
+        group.bench_with_input(
+            BenchmarkId::new("par_in_place", num_vars),
+            &num_vars,
+            |b, _| {
+                b.iter_batched(
+                    || mle.clone(),
+                    |mut table| {
+                        for _ in 0..num_vars {
+                            table.par_fix_variable_in_place(r);
+                        }
+                        table
+                    },
+                    BatchSize::LargeInput,
+                )
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_fold_all_variables);
+criterion_main!(benches);
@@ -0,0 +1,5 @@
+//! Multilinear polynomials for sumcheck-based proofs.
+
+pub mod multilinear;
+#[cfg(test)]
+pub(crate) mod test_utils;
@@ -0,0 +1,276 @@
+use ark_ff::Field;
+use std::fmt;
+
+mod arithmetic;
+mod coefficients;
+mod construction;
+mod eq;
+mod extension;
+mod fold;
+mod ordering;
+mod product;
+mod serialization;
+mod sparse;
+mod streaming;
+mod structure;
+mod virtual_polynomial;
+
+pub use coefficients::MultilinearCoeffForm;
+pub use field_codec::SERIALIZATION_VERSION;
+pub use ordering::VariableOrder;
+pub use product::ProductPoly;
+pub use sparse::SparseMultilinear;
+pub use streaming::{ChunkedMultilinear, StreamingMultilinear};
+pub use virtual_polynomial::VirtualPolynomial;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MultilinearError {
+    /// Two operands are defined over different numbers of variables.
+    DimensionMismatch { left: u32, right: u32 },
+    /// There are no values to build a table from.
+    EmptyTable,
+    /// A table over this many variables cannot be indexed by `usize`.
+    TooManyVariables(u32),
+    /// A corner was given with the wrong number of bits.
+    WrongBitLength { expected: u32, found: usize },
+    /// The same corner appears twice in a table.
+    DuplicateEntry(usize),
+    /// A product was built from no factors.
+    EmptyProduct,
+    /// An entry's index does not fit in a table over that many variables.
+    IndexOutOfRange {
+        index: usize,
+        number_of_variables: u32,
+    },
+}
+
+impl fmt::Display for MultilinearError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MultilinearError::DimensionMismatch { left, right } => {
+                write!(f, "operands have {left} and {right} variables respectively")
+            }
+            MultilinearError::EmptyTable => write!(f, "cannot build a table from no values"),
+            MultilinearError::TooManyVariables(n) => write!(f, "{n} variables is too many"),
+            MultilinearError::WrongBitLength { expected, found } => {
+                write!(f, "expected a corner with {expected} bits, found {found}")
+            }
+            MultilinearError::DuplicateEntry(index) => {
+                write!(f, "corner {index} appears more than once")
+            }
+            MultilinearError::EmptyProduct => write!(f, "a product needs at least one factor"),
+            MultilinearError::IndexOutOfRange {
+                index,
+                number_of_variables,
+            } => write!(
+                f,
+                "index {index} is out of range for {number_of_variables} variables"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MultilinearError {}
+
+/// A multilinear polynomial given by its values on the boolean hypercube.
+/// Index bits are read big-endian: variable 0 is the most significant bit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultilinearEvalForm<F: Field> {
+    evaluated_values: Vec<F>, // Values at hypercube corners
+}
+
+impl<F: Field> MultilinearEvalForm<F> {
+    pub fn new(evaluated_values: &[F]) -> Self {
+        assert!(
+            evaluated_values.len().is_power_of_two(),
+            "Number of evaluated values must be a power of 2"
+        );
+        Self {
+            evaluated_values: evaluated_values.to_vec(),
+        }
+    }
+
+    pub fn evaluate(&self, values: &[F]) -> F {
+        assert_eq!(
+            values.len() as u32,
+            self.number_of_variables(),
+            "Number of values must match number of variables"
+        );
+
+        // Binding variable 0 leaves the remaining ones renumbered from 0
+        let mut x_poly = self.evaluated_values.clone();
+        for &value in values {
+            x_poly = partial_evaluate(&x_poly, 0, value);
+        }
+        x_poly[0]
+    }
+
+    /// Fixes variable `eval_var` to `value`, returning the halved table.
+    pub fn partial_evaluate(&self, eval_var: usize, value: F) -> Vec<F> {
+        partial_evaluate(&self.evaluated_values, eval_var, value)
+    }
+
+    pub fn evaluated_values(&self) -> &[F] {
+        &self.evaluated_values
+    }
+
+    pub fn number_of_variables(&self) -> u32 {
+        self.evaluated_values.len().ilog2()
+    }
+
+    fn check_same_variables(&self, other: &Self) -> Result<(), MultilinearError> {
+        if self.number_of_variables() == other.number_of_variables() {
+            Ok(())
+        } else {
+            Err(MultilinearError::DimensionMismatch {
+                left: self.number_of_variables(),
+                right: other.number_of_variables(),
+            })
+        }
+    }
+}
+
+pub fn partial_evaluate<F: Field>(polynomial: &[F], eval_var: usize, value: F) -> Vec<F> {
+    fold_pairs(polynomial, eval_var, value, |x| x)
+}
+
+/// Fixes variable `eval_var` to `value` by folding every pair of entries that
+/// differ only in that variable, in index order of the remaining variables.
+/// Entries are mapped into the field of `value` with `embed`; the difference
+/// of each pair is taken in `F` first.
+fn fold_pairs<F: Field, E: Field>(
+    polynomial: &[F],
+    eval_var: usize,
+    value: E,
+    embed: impl Fn(F) -> E,
+) -> Vec<E> {
+    assert!(
+        polynomial.len().is_power_of_two(),
+        "Polynomial length must be a power of 2"
+    );
+
+    let poly_size = polynomial.len();
+    let expected_poly_size = poly_size / 2;
+    let number_of_variables = poly_size.ilog2() as usize;
+    assert!(
+        eval_var < number_of_variables,
+        "Variable index out of range"
+    );
+    let power = number_of_variables - 1 - eval_var;
+    let step = 1 << power;
+
+    let mut result_poly: Vec<E> = Vec::with_capacity(expected_poly_size);
+
+    let mut i = 0;
+    while i < poly_size {
+        let (first_pair, second_pair) = (polynomial[i], polynomial[i | step]);
+        result_poly.push(embed(first_pair) + value * embed(second_pair - first_pair));
+
+        i = if (i + 1) % step == 0 {
+            i + 1 + step
+        } else {
+            i + 1
+        };
+    }
+
+    assert_eq!(
+        result_poly.len(),
+        expected_poly_size,
+        "Result polynomial size mismatch"
+    );
+    result_poly
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr; // Using Fr instead of Fq for consistency
+
+    #[test]
+    fn test_partial_evaluate() {
+        let polynomial = vec![Fr::from(0), Fr::from(0), Fr::from(5), Fr::from(7)];
+        let mel = MultilinearEvalForm::new(&polynomial);
+
+        // Test partial evaluation for first variable
+        let result = partial_evaluate(&polynomial, 0, Fr::from(1));
+        assert_eq!(result.len(), 2);
+
+        // Test partial evaluation for second variable
+        let result = partial_evaluate(&polynomial, 1, Fr::from(1));
+        assert_eq!(result.len(), 2);
+
+        // Test full evaluation
+        let eval_point = vec![Fr::from(1), Fr::from(1)];
+        let result = mel.evaluate(&eval_point);
+        assert_eq!(result, Fr::from(7));
+    }
+
+    #[test]
+    fn test_evaluate_matches_corners() {
+        // f(a, b, c) at corner (a, b, c) is entry 4a + 2b + c
+        let values: Vec<Fr> = (0..8).map(|i| Fr::from(i * i + 1)).collect();
+        let mel = MultilinearEvalForm::new(&values);
+
+        for (index, &expected) in values.iter().enumerate() {
+            let point: Vec<Fr> = (0..3)
+                .map(|bit| Fr::from(((index >> (2 - bit)) & 1) as u64))
+                .collect();
+            assert_eq!(mel.evaluate(&point), expected);
+        }
+    }
+
+    #[test]
+    fn test_partial_evaluate_method() {
+        // f(a, b) = 5a + 2ab
+        let mel = MultilinearEvalForm::new(&[Fr::from(0), Fr::from(0), Fr::from(5), Fr::from(7)]);
+
+        // f(a, 3) = 5a + 6a
+        assert_eq!(
+            mel.partial_evaluate(1, Fr::from(3)),
+            vec![Fr::from(0), Fr::from(11)]
+        );
+        // f(3, b) = 15 + 6b
+        assert_eq!(
+            mel.partial_evaluate(0, Fr::from(3)),
+            vec![Fr::from(15), Fr::from(21)]
+        );
+        assert_eq!(mel.evaluated_values().len(), 4);
+        assert_eq!(mel.number_of_variables(), 2);
+    }
+
+    #[test]
+    fn test_convert_to_bytes() {
+        let mel = MultilinearEvalForm::new(&[Fr::from(1), Fr::from(2)]);
+        let bytes = mel.convert_to_bytes();
+
+        // version, u32 number of variables, then the values
+        assert_eq!(bytes.len(), 1 + 4 + 64);
+        assert_eq!(&bytes[1..5], &1u32.to_le_bytes());
+        assert_eq!(MultilinearEvalForm::from_bytes(&bytes).unwrap(), mel);
+
+        // Two one-variable tables no longer encode like one two-variable table
+        let other = MultilinearEvalForm::new(&[Fr::from(3), Fr::from(4)]);
+        let joined =
+            MultilinearEvalForm::new(&[Fr::from(1), Fr::from(2), Fr::from(3), Fr::from(4)]);
+        assert_ne!(
+            [bytes, other.convert_to_bytes()].concat(),
+            joined.convert_to_bytes()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Number of values must match number of variables")]
+    fn test_evaluate_wrong_number_of_variables() {
+        let polynomial = vec![Fr::from(0), Fr::from(0), Fr::from(5), Fr::from(7)];
+        let mel = MultilinearEvalForm::new(&polynomial);
+        let eval_point = vec![Fr::from(1)]; // Only one value when we need two
+        mel.evaluate(&eval_point);
+    }
+
+    #[test]
+    #[should_panic(expected = "Number of evaluated values must be a power of 2")]
+    fn test_invalid_polynomial_size() {
+        let polynomial = vec![Fr::from(0), Fr::from(0), Fr::from(5)]; // Size 3 is not a power of 2
+        MultilinearEvalForm::new(&polynomial);
+    }
+}
@@ -0,0 +1,135 @@
+use super::{MultilinearError, MultilinearEvalForm};
+use ark_ff::Field;
+use std::ops::{Add, Mul, Neg, Sub};
+
+// Linear combinations of MLEs are taken entry by entry on the hypercube.
+// Operands over different numbers of variables give `DimensionMismatch`.
+
+impl<F: Field> Add<&MultilinearEvalForm<F>> for &MultilinearEvalForm<F> {
+    type Output = Result<MultilinearEvalForm<F>, MultilinearError>;
+
+    fn add(self, rhs: &MultilinearEvalForm<F>) -> Self::Output {
+        self.check_same_variables(rhs)?;
+        Ok(MultilinearEvalForm {
+            evaluated_values: self
+                .evaluated_values
+                .iter()
+                .zip(&rhs.evaluated_values)
+                .map(|(&a, &b)| a + b)
+                .collect(),
+        })
+    }
+}
+
+impl<F: Field> Add for MultilinearEvalForm<F> {
+    type Output = Result<MultilinearEvalForm<F>, MultilinearError>;
+
+    fn add(self, rhs: MultilinearEvalForm<F>) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl<F: Field> Sub<&MultilinearEvalForm<F>> for &MultilinearEvalForm<F> {
+    type Output = Result<MultilinearEvalForm<F>, MultilinearError>;
+
+    fn sub(self, rhs: &MultilinearEvalForm<F>) -> Self::Output {
+        self.check_same_variables(rhs)?;
+        Ok(MultilinearEvalForm {
+            evaluated_values: self
+                .evaluated_values
+                .iter()
+                .zip(&rhs.evaluated_values)
+                .map(|(&a, &b)| a - b)
+                .collect(),
+        })
+    }
+}
+
+impl<F: Field> Sub for MultilinearEvalForm<F> {
+    type Output = Result<MultilinearEvalForm<F>, MultilinearError>;
+
+    fn sub(self, rhs: MultilinearEvalForm<F>) -> Self::Output {
+        &self - &rhs
+    }
+}
+
+impl<F: Field> Neg for &MultilinearEvalForm<F> {
+    type Output = MultilinearEvalForm<F>;
+
+    fn neg(self) -> MultilinearEvalForm<F> {
+        MultilinearEvalForm {
+            evaluated_values: self.evaluated_values.iter().map(|&a| -a).collect(),
+        }
+    }
+}
+
+impl<F: Field> Neg for MultilinearEvalForm<F> {
+    type Output = MultilinearEvalForm<F>;
+
+    fn neg(self) -> MultilinearEvalForm<F> {
+        -&self
+    }
+}
+
+impl<F: Field> Mul<F> for &MultilinearEvalForm<F> {
+    type Output = MultilinearEvalForm<F>;
+
+    fn mul(self, scalar: F) -> MultilinearEvalForm<F> {
+        MultilinearEvalForm {
+            evaluated_values: self.evaluated_values.iter().map(|&a| a * scalar).collect(),
+        }
+    }
+}
+
+impl<F: Field> Mul<F> for MultilinearEvalForm<F> {
+    type Output = MultilinearEvalForm<F>;
+
+    fn mul(self, scalar: F) -> MultilinearEvalForm<F> {
+        &self * scalar
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{random_mle, random_point};
+    use ark_bn254::Fr;
+    use ark_std::{test_rng, UniformRand};
+
+    #[test]
+    fn test_linear_combinations_commute_with_evaluation() {
+        let mut rng = test_rng();
+        let f = random_mle(3, &mut rng);
+        let g = random_mle(3, &mut rng);
+        let c = Fr::rand(&mut rng);
+        let point = random_point(3, &mut rng);
+
+        let (fr, gr) = (f.evaluate(&point), g.evaluate(&point));
+        assert_eq!((&f + &g).unwrap().evaluate(&point), fr + gr);
+        assert_eq!((&f - &g).unwrap().evaluate(&point), fr - gr);
+        assert_eq!((-&f).evaluate(&point), -fr);
+        assert_eq!((&f * c).evaluate(&point), c * fr);
+    }
+
+    #[test]
+    fn test_owned_operators() {
+        let mut rng = test_rng();
+        let f = random_mle(2, &mut rng);
+        let g = random_mle(2, &mut rng);
+
+        let sum = (f.clone() + g.clone()).unwrap();
+        assert_eq!((sum - g).unwrap(), f);
+        assert_eq!(-(f.clone() * Fr::from(2)), (-f.clone() - f).unwrap());
+    }
+
+    #[test]
+    fn test_dimension_mismatch() {
+        let mut rng = test_rng();
+        let f = random_mle(2, &mut rng);
+        let g = random_mle(3, &mut rng);
+
+        let expected = MultilinearError::DimensionMismatch { left: 2, right: 3 };
+        assert_eq!(&f + &g, Err(expected));
+        assert_eq!(&f - &g, Err(expected));
+    }
+}
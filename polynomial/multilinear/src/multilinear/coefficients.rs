@@ -0,0 +1,209 @@
+use super::MultilinearEvalForm;
+use ark_ff::Field;
+use std::fmt;
+
+/// A multilinear polynomial in the monomial basis. Entry `m` is the
+/// coefficient of the product of the variables whose bits are set in `m`,
+/// with the same big-endian convention as `MultilinearEvalForm`: variable 0
+/// is the most significant bit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultilinearCoeffForm<F: Field> {
+    coefficients: Vec<F>,
+}
+
+impl<F: Field> MultilinearCoeffForm<F> {
+    pub fn new(coefficients: &[F]) -> Self {
+        assert!(
+            coefficients.len().is_power_of_two(),
+            "Number of coefficients must be a power of 2"
+        );
+        Self {
+            coefficients: coefficients.to_vec(),
+        }
+    }
+
+    /// Builds `sum c * prod x_i` from `(c, [i, ...])` terms, e.g.
+    /// `3 x0 x2 + 5` is `[(3, vec![0, 2]), (5, vec![])]`.
+    pub fn from_terms(number_of_variables: u32, terms: &[(F, Vec<usize>)]) -> Self {
+        let mut coefficients = vec![F::zero(); 1 << number_of_variables];
+        for (coefficient, variables) in terms {
+            let mut monomial = 0;
+            for &variable in variables {
+                assert!(
+                    variable < number_of_variables as usize,
+                    "Variable index out of range"
+                );
+                let bit = 1 << (number_of_variables as usize - 1 - variable);
+                assert!(monomial & bit == 0, "Monomials must be multilinear");
+                monomial |= bit;
+            }
+            coefficients[monomial] += coefficient;
+        }
+        Self { coefficients }
+    }
+
+    pub fn coefficients(&self) -> &[F] {
+        &self.coefficients
+    }
+
+    pub fn number_of_variables(&self) -> u32 {
+        self.coefficients.len().ilog2()
+    }
+
+    pub fn evaluate(&self, values: &[F]) -> F {
+        assert_eq!(
+            values.len() as u32,
+            self.number_of_variables(),
+            "Number of values must match number of variables"
+        );
+
+        // c(x_0, rest) = c_0(rest) + x_0 c_1(rest), one variable at a time
+        let mut table = self.coefficients.clone();
+        for &value in values {
+            let half = table.len() / 2;
+            let (low, high) = table.split_at_mut(half);
+            for (l, &h) in low.iter_mut().zip(high.iter()) {
+                *l += value * h;
+            }
+            table.truncate(half);
+        }
+        table[0]
+    }
+}
+
+/// Zeta transform over the subset lattice: every corner becomes the sum of
+/// the coefficients of the monomials it switches on. O(n 2^n).
+fn zeta_transform<F: Field>(values: &mut [F]) {
+    let mut bit = 1;
+    while bit < values.len() {
+        for index in 0..values.len() {
+            if index & bit != 0 {
+                let lower = values[index ^ bit];
+                values[index] += lower;
+            }
+        }
+        bit <<= 1;
+    }
+}
+
+/// Inverse of `zeta_transform`.
+fn mobius_transform<F: Field>(values: &mut [F]) {
+    let mut bit = 1;
+    while bit < values.len() {
+        for index in 0..values.len() {
+            if index & bit != 0 {
+                let lower = values[index ^ bit];
+                values[index] -= lower;
+            }
+        }
+        bit <<= 1;
+    }
+}
+
+impl<F: Field> From<&MultilinearCoeffForm<F>> for MultilinearEvalForm<F> {
+    fn from(poly: &MultilinearCoeffForm<F>) -> Self {
+        let mut evaluated_values = poly.coefficients.clone();
+        zeta_transform(&mut evaluated_values);
+        MultilinearEvalForm { evaluated_values }
+    }
+}
+
+impl<F: Field> From<&MultilinearEvalForm<F>> for MultilinearCoeffForm<F> {
+    fn from(poly: &MultilinearEvalForm<F>) -> Self {
+        let mut coefficients = poly.evaluated_values.clone();
+        mobius_transform(&mut coefficients);
+        MultilinearCoeffForm { coefficients }
+    }
+}
+
+/// Prints the non-zero terms, e.g. `3*x0*x2 + 5`.
+impl<F: Field> fmt::Display for MultilinearCoeffForm<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let n = self.number_of_variables() as usize;
+        let mut first = true;
+        // Highest monomials first, so the constant term comes last
+        for (monomial, coefficient) in self.coefficients.iter().enumerate().rev() {
+            if coefficient.is_zero() {
+                continue;
+            }
+            if !first {
+                write!(f, " + ")?;
+            }
+            first = false;
+
+            write!(f, "{coefficient}")?;
+            for variable in 0..n {
+                if monomial & (1 << (n - 1 - variable)) != 0 {
+                    write!(f, "*x{variable}")?;
+                }
+            }
+        }
+        if first {
+            write!(f, "0")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::random_values;
+    use ark_bn254::Fr;
+    use ark_std::test_rng;
+
+    #[test]
+    fn test_from_terms() {
+        // 3 x0 x2 + 5 over three variables
+        let poly = MultilinearCoeffForm::from_terms(
+            3,
+            &[(Fr::from(3), vec![0, 2]), (Fr::from(5), vec![])],
+        );
+        assert_eq!(poly.coefficients()[0b101], Fr::from(3));
+        assert_eq!(poly.coefficients()[0], Fr::from(5));
+        assert_eq!(poly.to_string(), "3*x0*x2 + 5");
+
+        let point = [Fr::from(2), Fr::from(7), Fr::from(4)];
+        assert_eq!(poly.evaluate(&point), Fr::from(29));
+        assert_eq!(
+            MultilinearEvalForm::from(&poly).evaluate(&point),
+            Fr::from(29)
+        );
+    }
+
+    #[test]
+    fn test_round_trip_at_random_points() {
+        let mut rng = test_rng();
+        for num_vars in 0..6 {
+            let values = random_values(1 << num_vars, &mut rng);
+            let evals = MultilinearEvalForm::new(&values);
+            let coeffs = MultilinearCoeffForm::from(&evals);
+
+            assert_eq!(MultilinearEvalForm::from(&coeffs), evals);
+            for _ in 0..3 {
+                let point = random_values(num_vars, &mut rng);
+                assert_eq!(coeffs.evaluate(&point), evals.evaluate(&point));
+            }
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            MultilinearCoeffForm::new(&[Fr::from(0), Fr::from(0)]).to_string(),
+            "0"
+        );
+        // f(a, b) = 5a + 2ab on the corners (0, 0, 5, 7)
+        let evals = MultilinearEvalForm::new(&[Fr::from(0), Fr::from(0), Fr::from(5), Fr::from(7)]);
+        assert_eq!(
+            MultilinearCoeffForm::from(&evals).to_string(),
+            "2*x0*x1 + 5*x0"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Monomials must be multilinear")]
+    fn test_from_terms_rejects_repeated_variable() {
+        MultilinearCoeffForm::from_terms(2, &[(Fr::from(1), vec![1, 1])]);
+    }
+}
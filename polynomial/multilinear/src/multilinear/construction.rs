@@ -0,0 +1,162 @@
+use super::{MultilinearError, MultilinearEvalForm};
+use ark_ff::Field;
+
+// Fallible constructors for tables whose size is not already a power of two.
+// Corners are given as bit vectors with `bits[i]` the value of variable i,
+// matching the big-endian index convention of `MultilinearEvalForm`.
+
+impl<F: Field> MultilinearEvalForm<F> {
+    /// Lifts `values` to an MLE by padding with zeros up to the next power of
+    /// two, so entry `i` is still the value at corner `i`.
+    pub fn from_padded(values: &[F]) -> Result<Self, MultilinearError> {
+        if values.is_empty() {
+            return Err(MultilinearError::EmptyTable);
+        }
+        let size = values.len().next_power_of_two();
+        let mut evaluated_values = Vec::with_capacity(size);
+        evaluated_values.extend_from_slice(values);
+        evaluated_values.resize(size, F::zero());
+        Ok(MultilinearEvalForm { evaluated_values })
+    }
+
+    /// Tabulates `f` at every corner of the `number_of_variables`-cube.
+    pub fn from_fn(
+        number_of_variables: u32,
+        f: impl Fn(&[bool]) -> F,
+    ) -> Result<Self, MultilinearError> {
+        let size = table_size(number_of_variables)?;
+        let n = number_of_variables as usize;
+        let mut bits = vec![false; n];
+        let evaluated_values = (0..size)
+            .map(|index| {
+                for (variable, bit) in bits.iter_mut().enumerate() {
+                    *bit = index & (1 << (n - 1 - variable)) != 0;
+                }
+                f(&bits)
+            })
+            .collect();
+        Ok(MultilinearEvalForm { evaluated_values })
+    }
+
+    /// Builds a table from `(corner, value)` rows; corners not listed are
+    /// zero. Each corner may appear at most once.
+    pub fn from_bits_table(
+        number_of_variables: u32,
+        table: &[(Vec<bool>, F)],
+    ) -> Result<Self, MultilinearError> {
+        let size = table_size(number_of_variables)?;
+        let mut evaluated_values = vec![F::zero(); size];
+        let mut seen = vec![false; size];
+        for (bits, value) in table {
+            if bits.len() != number_of_variables as usize {
+                return Err(MultilinearError::WrongBitLength {
+                    expected: number_of_variables,
+                    found: bits.len(),
+                });
+            }
+            let index = bits
+                .iter()
+                .fold(0, |acc, &bit| (acc << 1) | usize::from(bit));
+            if seen[index] {
+                return Err(MultilinearError::DuplicateEntry(index));
+            }
+            seen[index] = true;
+            evaluated_values[index] = *value;
+        }
+        Ok(MultilinearEvalForm { evaluated_values })
+    }
+}
+
+pub(super) fn table_size(number_of_variables: u32) -> Result<usize, MultilinearError> {
+    if number_of_variables >= usize::BITS {
+        return Err(MultilinearError::TooManyVariables(number_of_variables));
+    }
+    Ok(1 << number_of_variables)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+
+    #[test]
+    fn test_from_padded() {
+        let values: Vec<Fr> = (1..=5).map(Fr::from).collect();
+        let mle = MultilinearEvalForm::from_padded(&values).unwrap();
+
+        assert_eq!(mle.number_of_variables(), 3);
+        assert_eq!(&mle.evaluated_values()[..5], &values[..]);
+        assert!(mle.evaluated_values()[5..]
+            .iter()
+            .all(|v| *v == Fr::from(0)));
+
+        let exact: Vec<Fr> = (1..=4).map(Fr::from).collect();
+        assert_eq!(
+            MultilinearEvalForm::from_padded(&exact).unwrap(),
+            MultilinearEvalForm::new(&exact)
+        );
+        assert_eq!(
+            MultilinearEvalForm::<Fr>::from_padded(&[]),
+            Err(MultilinearError::EmptyTable)
+        );
+    }
+
+    #[test]
+    fn test_from_fn() {
+        // AND of three bits is the monomial x0 x1 x2
+        let and = MultilinearEvalForm::from_fn(3, |bits| Fr::from(bits.iter().all(|&b| b) as u64))
+            .unwrap();
+        let point = [Fr::from(2), Fr::from(3), Fr::from(5)];
+        assert_eq!(and.evaluate(&point), Fr::from(30));
+
+        // Variable 0 is the most significant bit of the index
+        let first = MultilinearEvalForm::from_fn(2, |bits| Fr::from(bits[0] as u64)).unwrap();
+        assert_eq!(
+            first.evaluated_values(),
+            &[Fr::from(0), Fr::from(0), Fr::from(1), Fr::from(1)]
+        );
+        assert_eq!(
+            MultilinearEvalForm::from_fn(usize::BITS, |_| Fr::from(0)),
+            Err(MultilinearError::TooManyVariables(usize::BITS))
+        );
+    }
+
+    #[test]
+    fn test_from_bits_table() {
+        let mle = MultilinearEvalForm::from_bits_table(
+            3,
+            &[
+                (vec![true, false, true], Fr::from(7)),
+                (vec![false, true, false], Fr::from(2)),
+            ],
+        )
+        .unwrap();
+        assert_eq!(mle.evaluated_values()[0b101], Fr::from(7));
+        assert_eq!(mle.evaluated_values()[0b010], Fr::from(2));
+        assert_eq!(
+            mle.evaluated_values()
+                .iter()
+                .filter(|v| **v != Fr::from(0))
+                .count(),
+            2
+        );
+
+        assert_eq!(
+            MultilinearEvalForm::from_bits_table(2, &[(vec![true], Fr::from(1))]),
+            Err(MultilinearError::WrongBitLength {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            MultilinearEvalForm::from_bits_table(
+                2,
+                &[
+                    (vec![true, false], Fr::from(1)),
+                    (vec![true, false], Fr::from(2))
+                ]
+            ),
+            Err(MultilinearError::DuplicateEntry(0b10))
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use super::MultilinearEvalForm;
+use ark_ff::Field;
+
+impl<F: Field> MultilinearEvalForm<F> {
+    /// `eq(x, r) = prod (x_i r_i + (1 - x_i)(1 - r_i))` tabulated over the
+    /// hypercube, so that `evaluate(r)` is the dot product of a table with it.
+    /// Each variable doubles the table, splitting every entry `e` into
+    /// `e (1 - r_i)` and `e r_i`: 2^(n+1) multiplications in total.
+    pub fn eq_table(r: &[F]) -> Self {
+        let mut table = Vec::with_capacity(1 << r.len());
+        table.push(F::one());
+        for &r_i in r {
+            let mut doubled = Vec::with_capacity(table.len() * 2);
+            for &e in &table {
+                let high = e * r_i;
+                doubled.push(e - high);
+                doubled.push(high);
+            }
+            table = doubled;
+        }
+        MultilinearEvalForm {
+            evaluated_values: table,
+        }
+    }
+
+    /// `eq(x, r)` at a single pair of points, in O(n).
+    pub fn eq_eval(x: &[F], r: &[F]) -> F {
+        assert_eq!(
+            x.len(),
+            r.len(),
+            "Points must have the same number of variables"
+        );
+        x.iter()
+            .zip(r)
+            .map(|(&x_i, &r_i)| x_i * r_i + (F::one() - x_i) * (F::one() - r_i))
+            .product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{random_mle, random_point};
+    use ark_bn254::Fr;
+    use ark_std::test_rng;
+
+    #[test]
+    fn test_eq_table_is_kronecker_delta_on_hypercube() {
+        let corner = [Fr::from(1), Fr::from(0), Fr::from(1)];
+        let table = MultilinearEvalForm::eq_table(&corner);
+
+        for (index, &value) in table.evaluated_values().iter().enumerate() {
+            let expected = if index == 0b101 { 1 } else { 0 };
+            assert_eq!(value, Fr::from(expected));
+        }
+        assert_eq!(
+            MultilinearEvalForm::<Fr>::eq_table(&[]).evaluated_values(),
+            &[Fr::from(1)]
+        );
+    }
+
+    #[test]
+    fn test_eq_table_dot_product_is_evaluation() {
+        let mut rng = test_rng();
+        for num_vars in 1..6 {
+            let mle = random_mle(num_vars as u32, &mut rng);
+            let r = random_point(num_vars, &mut rng);
+
+            let dot: Fr = MultilinearEvalForm::eq_table(&r)
+                .evaluated_values()
+                .iter()
+                .zip(mle.evaluated_values())
+                .map(|(&e, &v)| e * v)
+                .sum();
+            assert_eq!(dot, mle.evaluate(&r));
+        }
+    }
+
+    #[test]
+    fn test_eq_eval_matches_table() {
+        let mut rng = test_rng();
+        let r = random_point(4, &mut rng);
+        let x = random_point(4, &mut rng);
+
+        let table = MultilinearEvalForm::eq_table(&r);
+        assert_eq!(table.evaluate(&x), MultilinearEvalForm::eq_eval(&x, &r));
+        assert_eq!(
+            MultilinearEvalForm::eq_eval(&x, &r),
+            MultilinearEvalForm::eq_eval(&r, &x)
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Points must have the same number of variables")]
+    fn test_eq_eval_length_mismatch() {
+        MultilinearEvalForm::eq_eval(&[Fr::from(1)], &[Fr::from(1), Fr::from(0)]);
+    }
+}
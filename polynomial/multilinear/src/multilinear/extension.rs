@@ -0,0 +1,282 @@
+use super::{fold_pairs, MultilinearEvalForm};
+use ark_ff::{Field, PrimeField};
+
+// Small-field proving: the table stays in the base field `F` while the
+// evaluation point comes from a larger field `E`. Only the first fold mixes
+// the two fields; from then on the table lives in `E` and the same-field
+// methods apply. arkworks extension fields do not implement `From<F>`, so the
+// embedding is passed explicitly, which also covers towers such as a table
+// over a quadratic extension evaluated at a point of a quartic one.
+
+impl<F: Field> MultilinearEvalForm<F> {
+    /// `partial_evaluate` at a point of a field `E` that the entries embed
+    /// into through `embed`.
+    pub fn partial_evaluate_embedded<E: Field>(
+        &self,
+        eval_var: usize,
+        value: E,
+        embed: impl Fn(F) -> E,
+    ) -> MultilinearEvalForm<E> {
+        MultilinearEvalForm {
+            evaluated_values: fold_pairs(&self.evaluated_values, eval_var, value, embed),
+        }
+    }
+
+    /// `evaluate` at a point of a field `E` that the entries embed into
+    /// through `embed`.
+    pub fn evaluate_embedded<E: Field>(&self, values: &[E], embed: impl Fn(F) -> E) -> E {
+        assert_eq!(
+            values.len() as u32,
+            self.number_of_variables(),
+            "Number of values must match number of variables"
+        );
+        match values.split_first() {
+            None => embed(self.evaluated_values[0]),
+            Some((&first, rest)) => self
+                .partial_evaluate_embedded(0, first, embed)
+                .evaluate(rest),
+        }
+    }
+}
+
+impl<F: PrimeField> MultilinearEvalForm<F> {
+    /// `partial_evaluate_embedded` for a table over the prime field of `E`.
+    pub fn partial_evaluate_ext<E: Field<BasePrimeField = F>>(
+        &self,
+        eval_var: usize,
+        value: E,
+    ) -> MultilinearEvalForm<E> {
+        self.partial_evaluate_embedded(eval_var, value, E::from_base_prime_field)
+    }
+
+    /// `evaluate_embedded` for a table over the prime field of `E`.
+    pub fn evaluate_ext<E: Field<BasePrimeField = F>>(&self, values: &[E]) -> E {
+        self.evaluate_embedded(values, E::from_base_prime_field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::multilinear::VirtualPolynomial;
+    use crate::test_utils::{random_elements, random_table};
+    use ark_ff::{
+        AdditiveGroup, Fp2, Fp2Config, Fp4, Fp4Config, Fp64, MontBackend, MontConfig, MontFp,
+    };
+    use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+    use ark_std::{test_rng, UniformRand};
+    use std::sync::Arc;
+
+    #[derive(MontConfig)]
+    #[modulus = "18446744069414584321"]
+    #[generator = "7"]
+    struct GoldilocksConfig;
+    type Goldilocks = Fp64<MontBackend<GoldilocksConfig, 1>>;
+
+    // Goldilocks[X] / (X^2 - 7)
+    struct Goldilocks2Config;
+    impl Fp2Config for Goldilocks2Config {
+        type Fp = Goldilocks;
+        const NONRESIDUE: Goldilocks = MontFp!("7");
+        const FROBENIUS_COEFF_FP2_C1: &'static [Goldilocks] =
+            &[MontFp!("1"), MontFp!("18446744069414584320")];
+    }
+    type Goldilocks2 = Fp2<Goldilocks2Config>;
+
+    // Goldilocks2[Y] / (Y^2 - X), a tower whose base prime field is still
+    // Goldilocks
+    struct Goldilocks4Config;
+    impl Fp4Config for Goldilocks4Config {
+        type Fp2Config = Goldilocks2Config;
+        const NONRESIDUE: Goldilocks2 = Goldilocks2::new(MontFp!("0"), MontFp!("1"));
+        const FROBENIUS_COEFF_FP4_C1: &'static [Goldilocks] = &[
+            MontFp!("1"),
+            MontFp!("281474976710656"),
+            MontFp!("18446744069414584320"),
+            MontFp!("18446462594437873665"),
+        ];
+    }
+    type Goldilocks4 = Fp4<Goldilocks4Config>;
+
+    fn lift(mle: &MultilinearEvalForm<Goldilocks>) -> MultilinearEvalForm<Goldilocks2> {
+        let values: Vec<Goldilocks2> = mle
+            .evaluated_values()
+            .iter()
+            .map(|&v| Goldilocks2::from_base_prime_field(v))
+            .collect();
+        MultilinearEvalForm::new(&values)
+    }
+
+    #[test]
+    fn test_extension_is_a_field() {
+        assert!(Goldilocks::from(7u64).legendre().is_qnr());
+        let mut rng = test_rng();
+        let x = Goldilocks2::rand(&mut rng);
+        assert_eq!(x * x.inverse().unwrap(), Goldilocks2::ONE);
+
+        let y = Goldilocks4::rand(&mut rng);
+        assert_eq!(y * y.inverse().unwrap(), Goldilocks4::ONE);
+        let mut frobenius = y;
+        frobenius.frobenius_map_in_place(1);
+        assert_eq!(frobenius, y.pow(Goldilocks::MODULUS));
+    }
+
+    #[test]
+    fn test_evaluate_ext_matches_lifted_table() {
+        let mut rng = test_rng();
+        let mle = random_table::<Goldilocks>(5, &mut rng);
+        let point: Vec<Goldilocks2> = random_elements(5, &mut rng);
+
+        assert_eq!(mle.evaluate_ext(&point), lift(&mle).evaluate(&point));
+
+        // Base-field points embed to the ordinary evaluation
+        let base: Vec<Goldilocks> = random_elements(5, &mut rng);
+        let embedded: Vec<Goldilocks2> = base
+            .iter()
+            .map(|&b| Goldilocks2::from_base_prime_field(b))
+            .collect();
+        assert_eq!(
+            mle.evaluate_ext(&embedded),
+            Goldilocks2::from_base_prime_field(mle.evaluate(&base))
+        );
+        assert_eq!(mle.evaluate_ext(&base), mle.evaluate(&base));
+    }
+
+    #[test]
+    fn test_partial_evaluate_ext() {
+        let mut rng = test_rng();
+        let mle = random_table::<Goldilocks>(4, &mut rng);
+        let r = Goldilocks2::rand(&mut rng);
+
+        for eval_var in 0..4 {
+            let folded = mle.partial_evaluate_ext(eval_var, r);
+            assert_eq!(
+                folded.evaluated_values(),
+                lift(&mle).partial_evaluate(eval_var, r)
+            );
+        }
+    }
+
+    #[test]
+    fn test_extension_tables_support_the_full_api() {
+        // After the first fold everything lives in the extension, so eq
+        // tables, arithmetic and virtual polynomials must all work there
+        let mut rng = test_rng();
+        let (f, g) = (
+            random_table::<Goldilocks>(4, &mut rng),
+            random_table::<Goldilocks>(4, &mut rng),
+        );
+        let point: Vec<Goldilocks2> = random_elements(4, &mut rng);
+        let (f1, g1) = (
+            f.partial_evaluate_ext(0, point[0]),
+            g.partial_evaluate_ext(0, point[0]),
+        );
+
+        let eq = MultilinearEvalForm::eq_table(&point[1..]);
+        let inner: Goldilocks2 = eq
+            .evaluated_values()
+            .iter()
+            .zip(f1.evaluated_values())
+            .map(|(e, v)| *e * v)
+            .sum();
+        assert_eq!(inner, f.evaluate_ext(&point));
+
+        let bytes = f1.convert_to_bytes();
+        assert_eq!(MultilinearEvalForm::from_bytes(&bytes).unwrap(), f1);
+        let mut uncompressed = Vec::new();
+        f1.serialize_uncompressed(&mut uncompressed).unwrap();
+        assert_eq!(
+            MultilinearEvalForm::deserialize_uncompressed(&uncompressed[..]).unwrap(),
+            f1
+        );
+
+        let sum = (&f1 + &g1).unwrap() * point[0];
+        assert_eq!(
+            sum.evaluate(&point[1..]),
+            point[0] * (f.evaluate_ext(&point) + g.evaluate_ext(&point))
+        );
+
+        let (f1, g1) = (Arc::new(f1), Arc::new(g1));
+        let mut poly = VirtualPolynomial::new(3);
+        poly.add_product(Goldilocks2::from(3u64), &[f1.clone(), g1.clone()])
+            .unwrap();
+        poly.add_product(Goldilocks2::ONE, std::slice::from_ref(&g1))
+            .unwrap();
+        assert_eq!(
+            poly.sum_over_hypercube(),
+            f1.hadamard(&g1).unwrap().sum_over_hypercube() * Goldilocks2::from(3u64)
+                + g1.evaluated_values().iter().sum::<Goldilocks2>()
+        );
+
+        let expected = poly.evaluate(&point[1..]);
+        for &r in &point[1..] {
+            poly.fix_variable_in_place(r);
+        }
+        assert_eq!(poly.evaluate(&[]), expected);
+        assert_eq!(
+            expected,
+            Goldilocks2::from(3u64) * f.evaluate_ext(&point) * g.evaluate_ext(&point)
+                + g.evaluate_ext(&point)
+        );
+    }
+
+    #[test]
+    fn test_evaluate_embedded_through_a_tower() {
+        // Goldilocks4 has Goldilocks, not Goldilocks2, as its base prime
+        // field, so only an explicit embedding can lift a Goldilocks2 table
+        let mut rng = test_rng();
+        let mle = random_table::<Goldilocks2>(4, &mut rng);
+        let point: Vec<Goldilocks4> = random_elements(4, &mut rng);
+        let embed = |x| Goldilocks4::new(x, Goldilocks2::ZERO);
+
+        let lifted = MultilinearEvalForm::new(
+            &mle.evaluated_values()
+                .iter()
+                .map(|&v| embed(v))
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(
+            mle.evaluate_embedded(&point, embed),
+            lifted.evaluate(&point)
+        );
+        for eval_var in 0..4 {
+            assert_eq!(
+                mle.partial_evaluate_embedded(eval_var, point[0], embed)
+                    .evaluated_values(),
+                lifted.partial_evaluate(eval_var, point[0])
+            );
+        }
+    }
+
+    #[test]
+    fn test_small_field_sumcheck() {
+        // Base-field witness, extension-field challenges: the first round is
+        // summed over Goldilocks, every later one over the extension
+        let mut rng = test_rng();
+        let mle = random_table::<Goldilocks>(6, &mut rng);
+        let half = mle.evaluated_values().len() / 2;
+
+        let zero: Goldilocks = mle.evaluated_values()[..half].iter().sum();
+        let one: Goldilocks = mle.evaluated_values()[half..].iter().sum();
+        let r = Goldilocks2::rand(&mut rng);
+        let mut claim =
+            Goldilocks2::from_base_prime_field(zero) + r.mul_by_base_prime_field(&(one - zero));
+        let mut challenges = vec![r];
+
+        let mut table = mle.partial_evaluate_ext(0, r);
+        while table.number_of_variables() > 0 {
+            let half = table.evaluated_values().len() / 2;
+            let zero: Goldilocks2 = table.evaluated_values()[..half].iter().sum();
+            let one: Goldilocks2 = table.evaluated_values()[half..].iter().sum();
+            assert_eq!(zero + one, claim);
+
+            let r = Goldilocks2::rand(&mut rng);
+            claim = zero + r * (one - zero);
+            challenges.push(r);
+            table.fix_variable_in_place(r);
+        }
+
+        assert_eq!(table.evaluated_values(), &[claim]);
+        assert_eq!(mle.evaluate_ext(&challenges), claim);
+    }
+}
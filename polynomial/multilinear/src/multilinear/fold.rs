@@ -0,0 +1,79 @@
+use super::MultilinearEvalForm;
+use ark_ff::Field;
+
+impl<F: Field> MultilinearEvalForm<F> {
+    /// Fixes variable 0 to `r` by folding the high half of the table onto the
+    /// low half and truncating, with no allocation. Same result as
+    /// `partial_evaluate(0, r)`; what is left is a function of the remaining
+    /// variables, renumbered from 0.
+    pub fn fix_variable_in_place(&mut self, r: F) {
+        assert!(self.number_of_variables() > 0, "No variables left to fix");
+        let half = self.evaluated_values.len() / 2;
+        let (low, high) = self.evaluated_values.split_at_mut(half);
+        for (l, &h) in low.iter_mut().zip(high.iter()) {
+            *l += r * (h - *l);
+        }
+        self.evaluated_values.truncate(half);
+    }
+
+    #[cfg(feature = "parallel")]
+    pub fn par_fix_variable_in_place(&mut self, r: F) {
+        use rayon::prelude::*;
+
+        assert!(self.number_of_variables() > 0, "No variables left to fix");
+        let half = self.evaluated_values.len() / 2;
+        let (low, high) = self.evaluated_values.split_at_mut(half);
+        low.par_iter_mut()
+            .zip(high.par_iter())
+            .for_each(|(l, &h)| *l += r * (h - *l));
+        self.evaluated_values.truncate(half);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{random_mle, random_point};
+    use ark_bn254::Fr;
+    use ark_std::test_rng;
+
+    #[test]
+    fn test_fix_variable_matches_partial_evaluate() {
+        let mut rng = test_rng();
+        let mle = random_mle(6, &mut rng);
+        let point = random_point(6, &mut rng);
+
+        let mut folded = mle.clone();
+        for (round, &r) in point.iter().enumerate() {
+            let expected = folded.partial_evaluate(0, r);
+            folded.fix_variable_in_place(r);
+            assert_eq!(folded.evaluated_values(), expected);
+            assert_eq!(folded.number_of_variables() as usize, 5 - round);
+        }
+        assert_eq!(folded.evaluated_values()[0], mle.evaluate(&point));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_par_fix_variable_matches_serial() {
+        use ark_std::UniformRand;
+
+        let mut rng = test_rng();
+        let mut serial = random_mle(10, &mut rng);
+        let mut parallel = serial.clone();
+
+        for _ in 0..10 {
+            let r = Fr::rand(&mut rng);
+            serial.fix_variable_in_place(r);
+            parallel.par_fix_variable_in_place(r);
+            assert_eq!(serial, parallel);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "No variables left to fix")]
+    fn test_fix_variable_on_constant() {
+        let mut constant = MultilinearEvalForm::new(&[Fr::from(3)]);
+        constant.fix_variable_in_place(Fr::from(1));
+    }
+}
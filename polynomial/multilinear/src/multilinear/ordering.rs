@@ -0,0 +1,231 @@
+use super::{partial_evaluate, MultilinearEvalForm};
+use ark_ff::Field;
+use std::cmp::Reverse;
+
+/// Which bit of a hypercube index holds variable 0.
+///
+/// `MsbFirst` is the convention used throughout the crate: binding variables
+/// 0, 1, ... folds the table from the top, halving it by splitting into a
+/// low and a high half. `LsbFirst` numbers variables from the bottom bit, so
+/// the same sequence of bindings folds adjacent pairs `(2j, 2j + 1)` instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VariableOrder {
+    #[default]
+    MsbFirst,
+    LsbFirst,
+}
+
+impl VariableOrder {
+    /// Bit position of `variable` in an index over `number_of_variables`.
+    pub fn bit(self, variable: usize, number_of_variables: usize) -> usize {
+        assert!(
+            variable < number_of_variables,
+            "Variable index out of range"
+        );
+        match self {
+            VariableOrder::MsbFirst => number_of_variables - 1 - variable,
+            VariableOrder::LsbFirst => variable,
+        }
+    }
+}
+
+impl<F: Field> MultilinearEvalForm<F> {
+    /// `partial_evaluate` with `eval_var` numbered according to `order`.
+    pub fn partial_evaluate_with_order(
+        &self,
+        eval_var: usize,
+        value: F,
+        order: VariableOrder,
+    ) -> Vec<F> {
+        let n = self.number_of_variables() as usize;
+        let msb_var = n - 1 - order.bit(eval_var, n);
+        partial_evaluate(&self.evaluated_values, msb_var, value)
+    }
+
+    /// `evaluate` with `values[i]` assigned to variable `i` under `order`.
+    pub fn evaluate_with_order(&self, values: &[F], order: VariableOrder) -> F {
+        match order {
+            VariableOrder::MsbFirst => self.evaluate(values),
+            VariableOrder::LsbFirst => {
+                let reversed: Vec<F> = values.iter().rev().copied().collect();
+                self.evaluate(&reversed)
+            }
+        }
+    }
+
+    /// Binds every `(variable, value)` pair in a single pass over the table.
+    /// The free variables keep their relative order and are renumbered from
+    /// 0 under the same `order`.
+    pub fn partial_evaluate_many(&self, bindings: &[(usize, F)], order: VariableOrder) -> Vec<F> {
+        let n = self.number_of_variables() as usize;
+
+        // Bound bit positions, most significant first
+        let mut bound: Vec<(usize, F)> = bindings
+            .iter()
+            .map(|&(variable, value)| (order.bit(variable, n), value))
+            .collect();
+        bound.sort_by_key(|&(bit, _)| Reverse(bit));
+        assert!(
+            bound.windows(2).all(|pair| pair[0].0 != pair[1].0),
+            "Variable bound more than once"
+        );
+        let free: Vec<usize> = (0..n)
+            .rev()
+            .filter(|bit| bound.iter().all(|&(b, _)| b != *bit))
+            .collect();
+
+        let bound_values: Vec<F> = bound.iter().map(|&(_, value)| value).collect();
+        let bound_bits: Vec<usize> = bound.iter().map(|&(bit, _)| bit).collect();
+        let eq = MultilinearEvalForm::eq_table(&bound_values);
+        let bound_offsets = scatter_indices(&bound_bits);
+        let free_offsets = scatter_indices(&free);
+
+        free_offsets
+            .iter()
+            .map(|&free_offset| {
+                eq.evaluated_values
+                    .iter()
+                    .zip(&bound_offsets)
+                    .map(|(&weight, &bound_offset)| {
+                        weight * self.evaluated_values[free_offset | bound_offset]
+                    })
+                    .sum()
+            })
+            .collect()
+    }
+}
+
+/// For every `k`-bit index, the full index with bit `t` (counted from the
+/// top) moved to position `bits[t]`.
+fn scatter_indices(bits: &[usize]) -> Vec<usize> {
+    let k = bits.len();
+    (0..1usize << k)
+        .map(|index| {
+            bits.iter()
+                .enumerate()
+                .filter(|&(t, _)| index & (1 << (k - 1 - t)) != 0)
+                .fold(0, |acc, (_, &bit)| acc | (1 << bit))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{random_mle, random_point};
+    use ark_bn254::Fr;
+    use ark_std::{test_rng, UniformRand};
+
+    #[test]
+    fn test_orders_agree_up_to_renumbering() {
+        let mut rng = test_rng();
+        let mle = random_mle(4, &mut rng);
+        let r = Fr::rand(&mut rng);
+
+        for variable in 0..4 {
+            assert_eq!(
+                mle.partial_evaluate_with_order(variable, r, VariableOrder::LsbFirst),
+                mle.partial_evaluate(3 - variable, r)
+            );
+        }
+        assert_eq!(
+            mle.partial_evaluate_with_order(1, r, VariableOrder::MsbFirst),
+            mle.partial_evaluate(1, r)
+        );
+    }
+
+    #[test]
+    fn test_lsb_first_folds_adjacent_pairs() {
+        // Binding LSB-first variable 0 combines entries (2j, 2j + 1)
+        let values: Vec<Fr> = (0..8).map(Fr::from).collect();
+        let mle = MultilinearEvalForm::new(&values);
+        let r = Fr::from(10);
+
+        let folded = mle.partial_evaluate_with_order(0, r, VariableOrder::LsbFirst);
+        for (j, &value) in folded.iter().enumerate() {
+            assert_eq!(
+                value,
+                values[2 * j] + r * (values[2 * j + 1] - values[2 * j])
+            );
+        }
+    }
+
+    #[test]
+    fn test_top_and_bottom_binding_strategies() {
+        // Sumcheck binding variable 0 of the shrinking table each round ends
+        // at the same value from either end once the point is reordered
+        let mut rng = test_rng();
+        let mle = random_mle(5, &mut rng);
+        let point = random_point(5, &mut rng);
+
+        for order in [VariableOrder::MsbFirst, VariableOrder::LsbFirst] {
+            let mut table = mle.clone();
+            for &r in &point {
+                table = MultilinearEvalForm::new(&table.partial_evaluate_with_order(0, r, order));
+            }
+            assert_eq!(
+                table.evaluated_values()[0],
+                mle.evaluate_with_order(&point, order)
+            );
+        }
+
+        let reversed: Vec<Fr> = point.iter().rev().copied().collect();
+        assert_eq!(
+            mle.evaluate_with_order(&point, VariableOrder::LsbFirst),
+            mle.evaluate(&reversed)
+        );
+    }
+
+    #[test]
+    fn test_partial_evaluate_many_matches_sequential() {
+        let mut rng = test_rng();
+        let mle = random_mle(5, &mut rng);
+        let (a, b) = (Fr::rand(&mut rng), Fr::rand(&mut rng));
+
+        // Binding variables 1 and 3 one at a time: after fixing 3, variable 1
+        // keeps its number under either order
+        for order in [VariableOrder::MsbFirst, VariableOrder::LsbFirst] {
+            let once = MultilinearEvalForm::new(&mle.partial_evaluate_with_order(3, b, order));
+            let sequential = once.partial_evaluate_with_order(1, a, order);
+            assert_eq!(
+                mle.partial_evaluate_many(&[(1, a), (3, b)], order),
+                sequential
+            );
+            assert_eq!(
+                mle.partial_evaluate_many(&[(3, b), (1, a)], order),
+                sequential
+            );
+        }
+    }
+
+    #[test]
+    fn test_partial_evaluate_many_edge_cases() {
+        let mut rng = test_rng();
+        let mle = random_mle(3, &mut rng);
+        let point = random_point(3, &mut rng);
+
+        assert_eq!(
+            mle.partial_evaluate_many(&[], VariableOrder::MsbFirst),
+            mle.evaluated_values()
+        );
+        let all: Vec<(usize, Fr)> = point.iter().copied().enumerate().collect();
+        assert_eq!(
+            mle.partial_evaluate_many(&all, VariableOrder::MsbFirst),
+            vec![mle.evaluate(&point)]
+        );
+        assert_eq!(
+            mle.partial_evaluate_many(&all, VariableOrder::LsbFirst),
+            vec![mle.evaluate_with_order(&point, VariableOrder::LsbFirst)]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Variable bound more than once")]
+    fn test_partial_evaluate_many_rejects_duplicates() {
+        let mle = MultilinearEvalForm::new(&[Fr::from(1), Fr::from(2), Fr::from(3), Fr::from(4)]);
+        mle.partial_evaluate_many(
+            &[(0, Fr::from(1)), (0, Fr::from(2))],
+            VariableOrder::MsbFirst,
+        );
+    }
+}
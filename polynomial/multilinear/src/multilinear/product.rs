@@ -0,0 +1,141 @@
+use super::{MultilinearError, MultilinearEvalForm};
+use ark_ff::Field;
+
+/// Pointwise product of MLEs over the same variables. The product has degree
+/// up to `degree()` in each variable, so the factors are kept separate
+/// rather than collapsed into a single (wrong) multilinear table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProductPoly<F: Field> {
+    factors: Vec<MultilinearEvalForm<F>>,
+}
+
+impl<F: Field> ProductPoly<F> {
+    pub fn new(factors: Vec<MultilinearEvalForm<F>>) -> Result<Self, MultilinearError> {
+        let (first, rest) = factors
+            .split_first()
+            .ok_or(MultilinearError::EmptyProduct)?;
+        for factor in rest {
+            first.check_same_variables(factor)?;
+        }
+        Ok(ProductPoly { factors })
+    }
+
+    pub fn factors(&self) -> &[MultilinearEvalForm<F>] {
+        &self.factors
+    }
+
+    pub fn number_of_variables(&self) -> u32 {
+        self.factors[0].number_of_variables()
+    }
+
+    /// Degree in each variable: one per factor.
+    pub fn degree(&self) -> usize {
+        self.factors.len()
+    }
+
+    /// Multiplies in one more factor.
+    pub fn hadamard(mut self, other: &MultilinearEvalForm<F>) -> Result<Self, MultilinearError> {
+        self.factors[0].check_same_variables(other)?;
+        self.factors.push(other.clone());
+        Ok(self)
+    }
+
+    pub fn evaluate(&self, values: &[F]) -> F {
+        self.factors
+            .iter()
+            .map(|factor| factor.evaluate(values))
+            .product()
+    }
+
+    /// Values of the product at the hypercube corners.
+    pub fn hypercube_values(&self) -> Vec<F> {
+        let mut values = self.factors[0].evaluated_values.clone();
+        for factor in &self.factors[1..] {
+            for (value, &f) in values.iter_mut().zip(&factor.evaluated_values) {
+                *value *= f;
+            }
+        }
+        values
+    }
+
+    pub fn sum_over_hypercube(&self) -> F {
+        self.hypercube_values().iter().sum()
+    }
+}
+
+impl<F: Field> MultilinearEvalForm<F> {
+    /// Pointwise product `self(x) * other(x)`.
+    pub fn hadamard(
+        &self,
+        other: &MultilinearEvalForm<F>,
+    ) -> Result<ProductPoly<F>, MultilinearError> {
+        ProductPoly::new(vec![self.clone(), other.clone()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{random_mle, random_point};
+    use ark_bn254::Fr;
+    use ark_std::test_rng;
+
+    #[test]
+    fn test_hadamard_evaluates_to_product() {
+        let mut rng = test_rng();
+        let f = random_mle(3, &mut rng);
+        let g = random_mle(3, &mut rng);
+        let h = random_mle(3, &mut rng);
+
+        let product = f.hadamard(&g).unwrap().hadamard(&h).unwrap();
+        assert_eq!(product.degree(), 3);
+        assert_eq!(product.number_of_variables(), 3);
+
+        let point = random_point(3, &mut rng);
+        assert_eq!(
+            product.evaluate(&point),
+            f.evaluate(&point) * g.evaluate(&point) * h.evaluate(&point)
+        );
+    }
+
+    #[test]
+    fn test_product_is_not_multilinear() {
+        // x * x agrees with x on the hypercube but not at x = 2
+        let x = MultilinearEvalForm::new(&[Fr::from(0), Fr::from(1)]);
+        let square = x.hadamard(&x).unwrap();
+
+        assert_eq!(square.hypercube_values(), x.evaluated_values());
+        assert_eq!(square.evaluate(&[Fr::from(2)]), Fr::from(4));
+        assert_eq!(
+            MultilinearEvalForm::new(&square.hypercube_values()).evaluate(&[Fr::from(2)]),
+            Fr::from(2)
+        );
+    }
+
+    #[test]
+    fn test_sum_over_hypercube() {
+        let f = MultilinearEvalForm::new(&[Fr::from(1), Fr::from(2), Fr::from(3), Fr::from(4)]);
+        let g = MultilinearEvalForm::new(&[Fr::from(5), Fr::from(6), Fr::from(7), Fr::from(8)]);
+
+        assert_eq!(f.hadamard(&g).unwrap().sum_over_hypercube(), Fr::from(70));
+    }
+
+    #[test]
+    fn test_hadamard_dimension_mismatch() {
+        let mut rng = test_rng();
+        let f = random_mle(2, &mut rng);
+        let g = random_mle(1, &mut rng);
+
+        let expected = MultilinearError::DimensionMismatch { left: 2, right: 1 };
+        assert_eq!(f.hadamard(&g), Err(expected));
+        assert_eq!(f.hadamard(&f).unwrap().hadamard(&g), Err(expected));
+    }
+
+    #[test]
+    fn test_empty_product() {
+        assert_eq!(
+            ProductPoly::<Fr>::new(vec![]),
+            Err(MultilinearError::EmptyProduct)
+        );
+    }
+}
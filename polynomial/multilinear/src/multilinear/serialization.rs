@@ -0,0 +1,230 @@
+use super::MultilinearEvalForm;
+use ark_ff::Field;
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+use field_codec::SERIALIZATION_VERSION;
+
+// Layout: version byte, number of variables as a u32, then the 2^n values in
+// index order in the requested compression mode. The length of the table is
+// implied by the header, so no separate length prefix is written.
+
+impl<F: Field> MultilinearEvalForm<F> {
+    /// The canonical compressed encoding. It starts with the number of
+    /// variables, so consecutive tables in a transcript cannot run together.
+    pub fn convert_to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.compressed_size());
+        self.serialize_compressed(&mut bytes)
+            .expect("serializing into a Vec cannot fail");
+        bytes
+    }
+
+    /// Reads a compressed, validated table as written by `convert_to_bytes`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SerializationError> {
+        Self::deserialize_compressed(bytes)
+    }
+}
+
+impl<F: Field> Valid for MultilinearEvalForm<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        if !self.evaluated_values.len().is_power_of_two() {
+            return Err(SerializationError::InvalidData);
+        }
+        self.evaluated_values.check()
+    }
+}
+
+impl<F: Field> CanonicalSerialize for MultilinearEvalForm<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        SERIALIZATION_VERSION.serialize_with_mode(&mut writer, compress)?;
+        self.number_of_variables()
+            .serialize_with_mode(&mut writer, compress)?;
+        for value in &self.evaluated_values {
+            value.serialize_with_mode(&mut writer, compress)?;
+        }
+        Ok(())
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        SERIALIZATION_VERSION.serialized_size(compress)
+            + self.number_of_variables().serialized_size(compress)
+            + self
+                .evaluated_values
+                .iter()
+                .map(|value| value.serialized_size(compress))
+                .sum::<usize>()
+    }
+}
+
+impl<F: Field> CanonicalDeserialize for MultilinearEvalForm<F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let version = u8::deserialize_with_mode(&mut reader, compress, validate)?;
+        if version != SERIALIZATION_VERSION {
+            return Err(SerializationError::InvalidData);
+        }
+        let number_of_variables = u32::deserialize_with_mode(&mut reader, compress, validate)?;
+        if number_of_variables >= usize::BITS {
+            return Err(SerializationError::InvalidData);
+        }
+
+        // Grow as values arrive rather than trusting the header with a huge
+        // up-front allocation; a short input fails on the first missing value
+        let mut evaluated_values = Vec::new();
+        for _ in 0..1usize << number_of_variables {
+            evaluated_values.push(F::deserialize_with_mode(&mut reader, compress, validate)?);
+        }
+        Ok(MultilinearEvalForm { evaluated_values })
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::{MultilinearEvalForm, SERIALIZATION_VERSION};
+    use ark_ff::PrimeField;
+    use field_codec::hex::{field_to_hex, hex_to_field};
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// JSON-friendly form: the header as plain numbers and the table in
+    /// index order, one hex string per corner.
+    #[derive(Serialize, Deserialize)]
+    struct HexTable {
+        version: u8,
+        num_vars: u32,
+        values: Vec<String>,
+    }
+
+    impl<F: PrimeField> Serialize for MultilinearEvalForm<F> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            HexTable {
+                version: SERIALIZATION_VERSION,
+                num_vars: self.number_of_variables(),
+                values: self.evaluated_values.iter().map(field_to_hex).collect(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de, F: PrimeField> Deserialize<'de> for MultilinearEvalForm<F> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let raw = HexTable::deserialize(deserializer)?;
+            if raw.version != SERIALIZATION_VERSION {
+                return Err(D::Error::custom(format!(
+                    "unsupported table version {}",
+                    raw.version
+                )));
+            }
+            if raw.num_vars >= usize::BITS || raw.values.len() != 1 << raw.num_vars {
+                return Err(D::Error::custom(format!(
+                    "{} values do not form a table over {} variables",
+                    raw.values.len(),
+                    raw.num_vars
+                )));
+            }
+
+            let evaluated_values = raw
+                .values
+                .iter()
+                .map(|s| hex_to_field(s))
+                .collect::<Result<Vec<F>, String>>()
+                .map_err(D::Error::custom)?;
+            Ok(MultilinearEvalForm { evaluated_values })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::random_mle;
+    use ark_bn254::Fr;
+    use ark_std::test_rng;
+
+    #[test]
+    fn test_round_trip_in_both_modes() {
+        for mle in [
+            random_mle(0, &mut test_rng()),
+            random_mle(5, &mut test_rng()),
+        ] {
+            for compress in [Compress::Yes, Compress::No] {
+                let mut bytes = Vec::new();
+                mle.serialize_with_mode(&mut bytes, compress).unwrap();
+                assert_eq!(bytes.len(), mle.serialized_size(compress));
+                assert_eq!(bytes[0], SERIALIZATION_VERSION);
+
+                let decoded = MultilinearEvalForm::<Fr>::deserialize_with_mode(
+                    &bytes[..],
+                    compress,
+                    Validate::Yes,
+                )
+                .unwrap();
+                assert_eq!(decoded, mle);
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        let mle = random_mle(3, &mut test_rng());
+        let bytes = mle.convert_to_bytes();
+        assert_eq!(MultilinearEvalForm::from_bytes(&bytes).unwrap(), mle);
+
+        // A header promising more variables than there are values
+        let mut lying = bytes.clone();
+        lying[1] = 4;
+        assert!(MultilinearEvalForm::<Fr>::from_bytes(&lying).is_err());
+        assert!(MultilinearEvalForm::<Fr>::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_rejects_bad_header() {
+        let mut bytes = random_mle(2, &mut test_rng()).convert_to_bytes();
+        bytes[0] = SERIALIZATION_VERSION + 1;
+        assert!(matches!(
+            MultilinearEvalForm::<Fr>::from_bytes(&bytes),
+            Err(SerializationError::InvalidData)
+        ));
+
+        let mut bytes = random_mle(2, &mut test_rng()).convert_to_bytes();
+        bytes[1..5].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(matches!(
+            MultilinearEvalForm::<Fr>::from_bytes(&bytes),
+            Err(SerializationError::InvalidData)
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_json_round_trip() {
+        let mle = random_mle(2, &mut test_rng());
+        let json = serde_json::to_string(&mle).unwrap();
+        assert!(json.contains("\"num_vars\":2"));
+
+        let decoded: MultilinearEvalForm<Fr> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, mle);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_rejects_bad_input() {
+        for json in [
+            // two values cannot form a table over two variables
+            r#"{"version":1,"num_vars":2,"values":["01","02"]}"#,
+            r#"{"version":1,"num_vars":64,"values":[]}"#,
+            r#"{"version":2,"num_vars":0,"values":["01"]}"#,
+            // element errors from the shared hex codec are passed through
+            r#"{"version":1,"num_vars":0,"values":["zz"]}"#,
+        ] {
+            assert!(serde_json::from_str::<MultilinearEvalForm<Fr>>(json).is_err());
+        }
+    }
+}
@@ -0,0 +1,249 @@
+use super::construction::table_size;
+use super::{MultilinearError, MultilinearEvalForm};
+use ark_ff::Field;
+use std::collections::BTreeMap;
+
+/// A multilinear extension stored as its non-zero hypercube entries
+/// `(index, value)`, sorted by index. Suited to R1CS matrices and lookup
+/// tables, where all but a handful of the `2^n` corners are zero.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseMultilinear<F: Field> {
+    number_of_variables: u32,
+    entries: Vec<(usize, F)>,
+}
+
+impl<F: Field> SparseMultilinear<F> {
+    /// Entries may come in any order; repeated indices are summed and zero
+    /// values dropped.
+    pub fn new(
+        number_of_variables: u32,
+        entries: Vec<(usize, F)>,
+    ) -> Result<Self, MultilinearError> {
+        let size = table_size(number_of_variables)?;
+        if let Some(&(index, _)) = entries.iter().find(|&&(index, _)| index >= size) {
+            return Err(MultilinearError::IndexOutOfRange {
+                index,
+                number_of_variables,
+            });
+        }
+        Ok(Self::normalized(number_of_variables, entries))
+    }
+
+    pub fn zero(number_of_variables: u32) -> Result<Self, MultilinearError> {
+        table_size(number_of_variables)?;
+        Ok(SparseMultilinear {
+            number_of_variables,
+            entries: Vec::new(),
+        })
+    }
+
+    /// `new` for arguments already known to be in range.
+    fn normalized(number_of_variables: u32, entries: Vec<(usize, F)>) -> Self {
+        let mut merged: BTreeMap<usize, F> = BTreeMap::new();
+        for (index, value) in entries {
+            *merged.entry(index).or_insert_with(F::zero) += value;
+        }
+        SparseMultilinear {
+            number_of_variables,
+            entries: merged.into_iter().filter(|(_, v)| !v.is_zero()).collect(),
+        }
+    }
+
+    pub fn number_of_variables(&self) -> u32 {
+        self.number_of_variables
+    }
+
+    pub fn entries(&self) -> &[(usize, F)] {
+        &self.entries
+    }
+
+    /// Number of non-zero entries.
+    pub fn nnz(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// `sum v * eq(index, r)` over the stored entries: O(nnz * n), without
+    /// ever building the 2^n eq table.
+    pub fn evaluate(&self, values: &[F]) -> F {
+        assert_eq!(
+            values.len() as u32,
+            self.number_of_variables,
+            "Number of values must match number of variables"
+        );
+
+        let n = values.len();
+        let one_minus: Vec<F> = values.iter().map(|&r| F::one() - r).collect();
+        self.entries
+            .iter()
+            .map(|&(index, value)| {
+                (0..n).fold(value, |acc, variable| {
+                    if index & (1 << (n - 1 - variable)) != 0 {
+                        acc * values[variable]
+                    } else {
+                        acc * one_minus[variable]
+                    }
+                })
+            })
+            .sum()
+    }
+
+    /// Fixes variable `eval_var` to `value`. Every entry lands on the index
+    /// with that bit removed, so the result has at most `nnz` entries.
+    ///
+    /// # Panics
+    ///
+    /// If `eval_var` is not one of the variables, like
+    /// `MultilinearEvalForm::partial_evaluate`.
+    pub fn partial_evaluate(&self, eval_var: usize, value: F) -> Self {
+        let n = self.number_of_variables as usize;
+        assert!(eval_var < n, "Variable index out of range");
+
+        let power = n - 1 - eval_var;
+        let low_mask = (1 << power) - 1;
+        let one_minus = F::one() - value;
+        let entries = self
+            .entries
+            .iter()
+            .map(|&(index, v)| {
+                let folded = ((index >> (power + 1)) << power) | (index & low_mask);
+                let weight = if index & (1 << power) != 0 {
+                    value
+                } else {
+                    one_minus
+                };
+                (folded, v * weight)
+            })
+            .collect();
+        SparseMultilinear::normalized(self.number_of_variables - 1, entries)
+    }
+}
+
+impl<F: Field> From<&SparseMultilinear<F>> for MultilinearEvalForm<F> {
+    fn from(sparse: &SparseMultilinear<F>) -> Self {
+        // `new` already checked that 2^n fits in a usize
+        let mut evaluated_values = vec![F::zero(); 1 << sparse.number_of_variables];
+        for &(index, value) in &sparse.entries {
+            evaluated_values[index] = value;
+        }
+        MultilinearEvalForm { evaluated_values }
+    }
+}
+
+impl<F: Field> From<&MultilinearEvalForm<F>> for SparseMultilinear<F> {
+    fn from(dense: &MultilinearEvalForm<F>) -> Self {
+        SparseMultilinear {
+            number_of_variables: dense.number_of_variables(),
+            entries: dense
+                .evaluated_values
+                .iter()
+                .copied()
+                .enumerate()
+                .filter(|(_, v)| !v.is_zero())
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{random_point, random_sparse};
+    use ark_bn254::Fr;
+    use ark_std::{test_rng, UniformRand};
+
+    #[test]
+    fn test_new_normalizes_entries() {
+        let sparse = SparseMultilinear::new(
+            3,
+            vec![
+                (5, Fr::from(2)),
+                (1, Fr::from(4)),
+                (5, -Fr::from(2)),
+                (6, Fr::from(0)),
+                (1, Fr::from(1)),
+            ],
+        )
+        .unwrap();
+        assert_eq!(sparse.entries(), &[(1, Fr::from(5))]);
+        assert_eq!(sparse.nnz(), 1);
+    }
+
+    #[test]
+    fn test_evaluate_matches_dense() {
+        let mut rng = test_rng();
+        for num_vars in 1..7 {
+            let sparse = random_sparse(num_vars, &mut rng);
+            let dense = MultilinearEvalForm::from(&sparse);
+            let point = random_point(num_vars as usize, &mut rng);
+
+            assert_eq!(sparse.evaluate(&point), dense.evaluate(&point));
+            assert_eq!(SparseMultilinear::from(&dense), sparse);
+        }
+    }
+
+    #[test]
+    fn test_partial_evaluate_matches_dense() {
+        let mut rng = test_rng();
+        let sparse = random_sparse(5, &mut rng);
+        let dense = MultilinearEvalForm::from(&sparse);
+
+        for eval_var in 0..5 {
+            let value = Fr::rand(&mut rng);
+            let folded = sparse.partial_evaluate(eval_var, value);
+
+            assert!(folded.nnz() <= sparse.nnz());
+            assert_eq!(folded.number_of_variables(), 4);
+            assert_eq!(
+                MultilinearEvalForm::from(&folded).evaluated_values(),
+                dense.partial_evaluate(eval_var, value)
+            );
+        }
+    }
+
+    #[test]
+    fn test_partial_evaluate_keeps_boolean_slices_sparse() {
+        // Fixing a variable to 0 or 1 just selects a half of the entries
+        let sparse =
+            SparseMultilinear::new(3, vec![(0b010, Fr::from(3)), (0b110, Fr::from(7))]).unwrap();
+
+        assert_eq!(
+            sparse.partial_evaluate(0, Fr::from(1)).entries(),
+            &[(0b10, Fr::from(7))]
+        );
+        assert_eq!(
+            sparse.partial_evaluate(0, Fr::from(0)).entries(),
+            &[(0b10, Fr::from(3))]
+        );
+        assert!(sparse.partial_evaluate(1, Fr::from(0)).entries().is_empty());
+    }
+
+    #[test]
+    fn test_index_out_of_range() {
+        assert_eq!(
+            SparseMultilinear::new(2, vec![(1, Fr::from(1)), (4, Fr::from(1))]),
+            Err(MultilinearError::IndexOutOfRange {
+                index: 4,
+                number_of_variables: 2
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Variable index out of range")]
+    fn test_partial_evaluate_out_of_range() {
+        random_sparse(2, &mut test_rng()).partial_evaluate(2, Fr::from(1));
+    }
+
+    #[test]
+    fn test_too_many_variables() {
+        assert_eq!(
+            SparseMultilinear::<Fr>::new(usize::BITS, vec![]),
+            Err(MultilinearError::TooManyVariables(usize::BITS))
+        );
+        assert_eq!(
+            SparseMultilinear::<Fr>::zero(usize::BITS),
+            Err(MultilinearError::TooManyVariables(usize::BITS))
+        );
+        assert_eq!(SparseMultilinear::<Fr>::zero(3).unwrap().nnz(), 0);
+    }
+}
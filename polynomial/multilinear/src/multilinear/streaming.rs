@@ -0,0 +1,296 @@
+use super::{MultilinearError, MultilinearEvalForm};
+use ark_ff::Field;
+
+/// A multilinear polynomial whose table is only ever seen as a stream of
+/// chunks in index order, so nothing proportional to `2^n` has to be held in
+/// memory. Every provided method is a single pass over the stream.
+pub trait StreamingMultilinear<F: Field> {
+    fn number_of_variables(&self) -> u32;
+
+    /// Feeds the whole table to `visit` as consecutive chunks, in index order.
+    fn for_each_chunk(&self, visit: &mut dyn FnMut(&[F]));
+
+    /// `f(values)` in O(2^n) time and O(n) memory.
+    fn evaluate(&self, values: &[F]) -> F {
+        assert_eq!(
+            values.len() as u32,
+            self.number_of_variables(),
+            "Number of values must match number of variables"
+        );
+        let mut folder = StackFolder::new(values);
+        self.for_each_chunk(&mut |chunk| chunk.iter().for_each(|&v| folder.push(v)));
+        folder.finish()
+    }
+
+    fn sum_over_hypercube(&self) -> F {
+        let mut sum = F::zero();
+        self.for_each_chunk(&mut |chunk| sum += chunk.iter().sum::<F>());
+        sum
+    }
+
+    /// The sumcheck round polynomial after binding the leading variables to
+    /// `challenges`, as its values `(g(0), g(1))` where
+    /// `g(X) = sum_x f(challenges, X, x)`. O(2^n) time, O(n) memory.
+    fn round_sums(&self, challenges: &[F]) -> (F, F) {
+        let n = self.number_of_variables() as usize;
+        assert!(challenges.len() < n, "No variables left to sum over");
+
+        // Blocks of 2^(n - j - 1) entries share their leading j + 1 bits; the
+        // block sums alternate between X = 0 and X = 1 and are then folded
+        // with the challenges like an ordinary evaluation
+        let block_size = 1usize << (n - challenges.len() - 1);
+        let mut folders = [StackFolder::new(challenges), StackFolder::new(challenges)];
+        let mut block_sum = F::zero();
+        let mut position = 0usize;
+        self.for_each_chunk(&mut |chunk| {
+            for &v in chunk {
+                block_sum += v;
+                position += 1;
+                if position.is_multiple_of(block_size) {
+                    let x = (position / block_size - 1) & 1;
+                    folders[x].push(block_sum);
+                    block_sum = F::zero();
+                }
+            }
+        });
+        let [zero, one] = folders;
+        (zero.finish(), one.finish())
+    }
+
+    /// Materialises `f(challenges, x)` as a table over the remaining
+    /// variables, using O(2^j + 2^(n - j)) memory for `j` challenges. A
+    /// prover streams the first n/2 rounds and then continues in memory.
+    fn fold_prefix(&self, challenges: &[F]) -> MultilinearEvalForm<F> {
+        let n = self.number_of_variables() as usize;
+        assert!(challenges.len() <= n, "More challenges than variables");
+
+        let eq = MultilinearEvalForm::eq_table(challenges);
+        let suffix_bits = n - challenges.len();
+        let mask = (1usize << suffix_bits) - 1;
+        let mut evaluated_values = vec![F::zero(); 1 << suffix_bits];
+        let mut index = 0usize;
+        self.for_each_chunk(&mut |chunk| {
+            for &v in chunk {
+                evaluated_values[index & mask] += eq.evaluated_values[index >> suffix_bits] * v;
+                index += 1;
+            }
+        });
+        MultilinearEvalForm { evaluated_values }
+    }
+}
+
+impl<F: Field> StreamingMultilinear<F> for MultilinearEvalForm<F> {
+    fn number_of_variables(&self) -> u32 {
+        MultilinearEvalForm::number_of_variables(self)
+    }
+
+    fn for_each_chunk(&self, visit: &mut dyn FnMut(&[F])) {
+        visit(&self.evaluated_values);
+    }
+}
+
+/// A table produced on demand, one chunk of about `sqrt(2^n)` entries at a
+/// time: `fill(offset, buffer)` must write the entries starting at `offset`.
+pub struct ChunkedMultilinear<F: Field, G: Fn(usize, &mut [F])> {
+    number_of_variables: u32,
+    chunk_size: usize,
+    fill: G,
+    _field: std::marker::PhantomData<F>,
+}
+
+impl<F: Field, G: Fn(usize, &mut [F])> ChunkedMultilinear<F, G> {
+    pub fn new(number_of_variables: u32, fill: G) -> Result<Self, MultilinearError> {
+        if number_of_variables >= usize::BITS {
+            return Err(MultilinearError::TooManyVariables(number_of_variables));
+        }
+        Ok(ChunkedMultilinear {
+            number_of_variables,
+            chunk_size: 1 << number_of_variables.div_ceil(2),
+            fill,
+            _field: std::marker::PhantomData,
+        })
+    }
+
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+}
+
+impl<F: Field, G: Fn(usize, &mut [F])> StreamingMultilinear<F> for ChunkedMultilinear<F, G> {
+    fn number_of_variables(&self) -> u32 {
+        self.number_of_variables
+    }
+
+    fn for_each_chunk(&self, visit: &mut dyn FnMut(&[F])) {
+        let size = 1usize << self.number_of_variables;
+        let mut buffer = vec![F::zero(); self.chunk_size.min(size)];
+        for offset in (0..size).step_by(buffer.len()) {
+            (self.fill)(offset, &mut buffer);
+            visit(&buffer);
+        }
+    }
+}
+
+/// Folds values arriving in index order with `challenges` (variable 0 being
+/// the most significant bit) like a binary counter: two partial results at
+/// the same level merge into one at the next, so at most one value per level
+/// is alive at any time.
+struct StackFolder<'a, F: Field> {
+    challenges: &'a [F],
+    stack: Vec<(usize, F)>,
+}
+
+impl<'a, F: Field> StackFolder<'a, F> {
+    fn new(challenges: &'a [F]) -> Self {
+        StackFolder {
+            challenges,
+            stack: Vec::with_capacity(challenges.len() + 1),
+        }
+    }
+
+    fn push(&mut self, value: F) {
+        let mut current = (0, value);
+        while let Some(&(level, low)) = self.stack.last() {
+            if level != current.0 {
+                break;
+            }
+            self.stack.pop();
+            let r = self.challenges[self.challenges.len() - 1 - level];
+            current = (level + 1, low + r * (current.1 - low));
+        }
+        self.stack.push(current);
+    }
+
+    fn finish(self) -> F {
+        assert!(
+            self.stack.len() == 1 && self.stack[0].0 == self.challenges.len(),
+            "Stream length does not match the number of variables"
+        );
+        self.stack[0].1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::random_values;
+    use ark_bn254::Fr;
+    use ark_std::{test_rng, UniformRand};
+
+    /// Entry `i` is `i^2 + 1`, generated chunk by chunk.
+    fn squares(num_vars: u32) -> ChunkedMultilinear<Fr, impl Fn(usize, &mut [Fr])> {
+        ChunkedMultilinear::new(num_vars, |offset, buffer: &mut [Fr]| {
+            for (i, value) in buffer.iter_mut().enumerate() {
+                let index = (offset + i) as u64;
+                *value = Fr::from(index * index + 1);
+            }
+        })
+        .unwrap()
+    }
+
+    /// Streams the first half of the rounds, then finishes in memory, and
+    /// checks every round as well as the final oracle query.
+    fn prove_and_verify(poly: &impl StreamingMultilinear<Fr>, rng: &mut impl ark_std::rand::Rng) {
+        let n = poly.number_of_variables() as usize;
+        let mut claim = poly.sum_over_hypercube();
+        let mut challenges = Vec::with_capacity(n);
+
+        for _ in 0..n / 2 {
+            let (zero, one) = poly.round_sums(&challenges);
+            assert_eq!(zero + one, claim);
+            let r = Fr::rand(rng);
+            claim = zero + r * (one - zero);
+            challenges.push(r);
+        }
+
+        let mut table = poly.fold_prefix(&challenges);
+        for _ in n / 2..n {
+            let half = table.evaluated_values().len() / 2;
+            let zero: Fr = table.evaluated_values()[..half].iter().sum();
+            let one: Fr = table.evaluated_values()[half..].iter().sum();
+            assert_eq!(zero + one, claim);
+            let r = Fr::rand(rng);
+            claim = zero + r * (one - zero);
+            challenges.push(r);
+            table.fix_variable_in_place(r);
+        }
+
+        assert_eq!(table.evaluated_values(), &[claim]);
+        assert_eq!(poly.evaluate(&challenges), claim);
+    }
+
+    #[test]
+    fn test_streaming_matches_in_memory() {
+        let mut rng = test_rng();
+        let values = random_values(1 << 7, &mut rng);
+        let mle = MultilinearEvalForm::new(&values);
+        let chunked = ChunkedMultilinear::new(7, |offset, buffer: &mut [Fr]| {
+            buffer.copy_from_slice(&values[offset..offset + buffer.len()])
+        })
+        .unwrap();
+        assert_eq!(chunked.chunk_size(), 16);
+
+        let point = random_values(7, &mut rng);
+        assert_eq!(
+            StreamingMultilinear::evaluate(&chunked, &point),
+            mle.evaluate(&point)
+        );
+        assert_eq!(chunked.sum_over_hypercube(), values.iter().sum::<Fr>());
+
+        for j in 0..7 {
+            let prefix = &point[..j];
+            let folded = MultilinearEvalForm::new(&mle.partial_evaluate_many(
+                &prefix.iter().copied().enumerate().collect::<Vec<_>>(),
+                Default::default(),
+            ));
+            assert_eq!(chunked.fold_prefix(prefix), folded);
+
+            let half = folded.evaluated_values().len() / 2;
+            assert_eq!(
+                chunked.round_sums(prefix),
+                (
+                    folded.evaluated_values()[..half].iter().sum(),
+                    folded.evaluated_values()[half..].iter().sum()
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn test_streaming_sumcheck() {
+        let mut rng = test_rng();
+        prove_and_verify(&squares(12), &mut rng);
+
+        let mle = MultilinearEvalForm::new(&random_values(1 << 5, &mut rng));
+        prove_and_verify(&mle, &mut rng);
+    }
+
+    #[test]
+    fn test_chunk_size_is_square_root() {
+        // The working set of every pass is one chunk, O(2^(n/2)) field elements
+        assert_eq!(squares(26).chunk_size(), 1 << 13);
+        assert_eq!(squares(7).chunk_size(), 1 << 4);
+        assert_eq!(squares(0).chunk_size(), 1);
+    }
+
+    #[test]
+    fn test_streaming_sumcheck_2_pow_20() {
+        // Large enough that the table is streamed in 2^10 chunks of 2^10
+        // entries, small enough for a debug build
+        let mut rng = test_rng();
+        let poly = squares(20);
+        assert_eq!(poly.chunk_size(), 1 << 10);
+        prove_and_verify(&poly, &mut rng);
+    }
+
+    // About a minute even with optimisations, so not part of the default
+    // run: cargo test --release -- --ignored
+    #[test]
+    #[ignore = "slow; run with cargo test --release -- --ignored"]
+    fn test_streaming_sumcheck_2_pow_26() {
+        let mut rng = test_rng();
+        let poly = squares(26);
+        assert_eq!(poly.chunk_size(), 1 << 13);
+        prove_and_verify(&poly, &mut rng);
+    }
+}
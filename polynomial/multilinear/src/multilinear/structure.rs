@@ -0,0 +1,150 @@
+use super::construction::table_size;
+use super::{MultilinearError, MultilinearEvalForm};
+use ark_ff::Field;
+
+// Structural operations that combine or take apart tables. New variables are
+// always placed first, i.e. in the most significant bits of the index.
+
+impl<F: Field> MultilinearEvalForm<F> {
+    /// `merge(f, g)(b, x) = (1 - b) f(x) + b g(x)`: the two tables stacked
+    /// one after the other, selected by a new leading variable.
+    pub fn merge(f: &Self, g: &Self) -> Result<Self, MultilinearError> {
+        f.check_same_variables(g)?;
+        let mut evaluated_values = Vec::with_capacity(2 * f.evaluated_values.len());
+        evaluated_values.extend_from_slice(&f.evaluated_values);
+        evaluated_values.extend_from_slice(&g.evaluated_values);
+        Ok(MultilinearEvalForm { evaluated_values })
+    }
+
+    /// The halves of the table on which `variable` is 0 and 1 respectively.
+    /// `merge` undoes `split(0)`.
+    pub fn split(&self, variable: usize) -> (Self, Self) {
+        (
+            MultilinearEvalForm {
+                evaluated_values: self.partial_evaluate(variable, F::zero()),
+            },
+            MultilinearEvalForm {
+                evaluated_values: self.partial_evaluate(variable, F::one()),
+            },
+        )
+    }
+
+    /// `tensor(f, g)(x, y) = f(x) g(y)`, with the variables of `f` first.
+    pub fn tensor(f: &Self, g: &Self) -> Result<Self, MultilinearError> {
+        let size = table_size(
+            f.number_of_variables()
+                .saturating_add(g.number_of_variables()),
+        )?;
+        let mut evaluated_values = Vec::with_capacity(size);
+        for &a in &f.evaluated_values {
+            evaluated_values.extend(g.evaluated_values.iter().map(|&b| a * b));
+        }
+        Ok(MultilinearEvalForm { evaluated_values })
+    }
+
+    /// `f(y, x) = f(x)`: adds `extra` leading variables the result does not
+    /// depend on.
+    pub fn extend_variables(&self, extra: u32) -> Result<Self, MultilinearError> {
+        table_size(self.number_of_variables().saturating_add(extra))?;
+        Ok(MultilinearEvalForm {
+            evaluated_values: self.evaluated_values.repeat(1 << extra),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{random_mle, random_point};
+    use ark_bn254::Fr;
+    use ark_std::{test_rng, UniformRand};
+
+    #[test]
+    fn test_merge_selects_with_leading_variable() {
+        let mut rng = test_rng();
+        let f = random_mle(3, &mut rng);
+        let g = random_mle(3, &mut rng);
+        let merged = MultilinearEvalForm::merge(&f, &g).unwrap();
+        assert_eq!(merged.number_of_variables(), 4);
+
+        let b = Fr::rand(&mut rng);
+        let x = random_point(3, &mut rng);
+        let point = [vec![b], x.clone()].concat();
+        assert_eq!(
+            merged.evaluate(&point),
+            (Fr::from(1) - b) * f.evaluate(&x) + b * g.evaluate(&x)
+        );
+
+        assert_eq!(
+            MultilinearEvalForm::merge(&f, &random_mle(2, &mut rng)),
+            Err(MultilinearError::DimensionMismatch { left: 3, right: 2 })
+        );
+    }
+
+    #[test]
+    fn test_split() {
+        let mut rng = test_rng();
+        let f = random_mle(4, &mut rng);
+
+        let (low, high) = f.split(0);
+        assert_eq!(MultilinearEvalForm::merge(&low, &high).unwrap(), f);
+
+        // Splitting on an inner variable interpolates back along it
+        let (zero, one) = f.split(2);
+        let r = Fr::rand(&mut rng);
+        let x = random_point(3, &mut rng);
+        let point = [x[0], x[1], r, x[2]];
+        assert_eq!(
+            f.evaluate(&point),
+            (Fr::from(1) - r) * zero.evaluate(&x) + r * one.evaluate(&x)
+        );
+    }
+
+    #[test]
+    fn test_tensor() {
+        let mut rng = test_rng();
+        let f = random_mle(2, &mut rng);
+        let g = random_mle(3, &mut rng);
+        let product = MultilinearEvalForm::tensor(&f, &g).unwrap();
+        assert_eq!(product.number_of_variables(), 5);
+
+        let x = random_point(2, &mut rng);
+        let y = random_point(3, &mut rng);
+        assert_eq!(
+            product.evaluate(&[x.clone(), y.clone()].concat()),
+            f.evaluate(&x) * g.evaluate(&y)
+        );
+
+        // eq tables factor as tensors of their halves
+        let r = random_point(5, &mut rng);
+        assert_eq!(
+            MultilinearEvalForm::eq_table(&r),
+            MultilinearEvalForm::tensor(
+                &MultilinearEvalForm::eq_table(&r[..2]),
+                &MultilinearEvalForm::eq_table(&r[2..])
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_extend_variables() {
+        let mut rng = test_rng();
+        let f = random_mle(2, &mut rng);
+        let extended = f.extend_variables(2).unwrap();
+        assert_eq!(extended.number_of_variables(), 4);
+
+        let x = random_point(2, &mut rng);
+        let y = random_point(2, &mut rng);
+        assert_eq!(extended.evaluate(&[y, x.clone()].concat()), f.evaluate(&x));
+
+        assert_eq!(
+            f.extend_variables(usize::BITS - 2),
+            Err(MultilinearError::TooManyVariables(usize::BITS))
+        );
+        assert_eq!(
+            f.extend_variables(u32::MAX),
+            Err(MultilinearError::TooManyVariables(u32::MAX))
+        );
+    }
+}
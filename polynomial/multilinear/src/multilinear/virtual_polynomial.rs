@@ -0,0 +1,296 @@
+use super::{MultilinearError, MultilinearEvalForm};
+use ark_ff::Field;
+use std::sync::Arc;
+
+/// `sum_j c_j prod_k f_jk(x)`: a sum of products of MLEs over the same
+/// variables, as proven by sumcheck. Each distinct MLE is stored once (MLEs
+/// are recognised by `Arc` identity), and products refer to them by index,
+/// so a table shared between several products is only folded once.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VirtualPolynomial<F: Field> {
+    number_of_variables: u32,
+    mles: Vec<Arc<MultilinearEvalForm<F>>>,
+    products: Vec<(F, Vec<usize>)>,
+}
+
+impl<F: Field> VirtualPolynomial<F> {
+    /// The zero polynomial over `number_of_variables` variables.
+    pub fn new(number_of_variables: u32) -> Self {
+        VirtualPolynomial {
+            number_of_variables,
+            mles: Vec::new(),
+            products: Vec::new(),
+        }
+    }
+
+    /// Adds `coefficient * prod factors`. An empty product is the constant
+    /// `coefficient`.
+    pub fn add_product(
+        &mut self,
+        coefficient: F,
+        factors: &[Arc<MultilinearEvalForm<F>>],
+    ) -> Result<(), MultilinearError> {
+        let mut indices = Vec::with_capacity(factors.len());
+        for factor in factors {
+            if factor.number_of_variables() != self.number_of_variables {
+                return Err(MultilinearError::DimensionMismatch {
+                    left: self.number_of_variables,
+                    right: factor.number_of_variables(),
+                });
+            }
+            let index = match self.mles.iter().position(|mle| Arc::ptr_eq(mle, factor)) {
+                Some(index) => index,
+                None => {
+                    self.mles.push(Arc::clone(factor));
+                    self.mles.len() - 1
+                }
+            };
+            indices.push(index);
+        }
+        self.products.push((coefficient, indices));
+        Ok(())
+    }
+
+    pub fn number_of_variables(&self) -> u32 {
+        self.number_of_variables
+    }
+
+    pub fn mles(&self) -> &[Arc<MultilinearEvalForm<F>>] {
+        &self.mles
+    }
+
+    /// `(coefficient, indices into mles())` for every product term.
+    pub fn products(&self) -> &[(F, Vec<usize>)] {
+        &self.products
+    }
+
+    /// Degree in each variable: the size of the largest product. This is the
+    /// degree of every sumcheck round polynomial.
+    pub fn degree(&self) -> usize {
+        self.products
+            .iter()
+            .map(|(_, indices)| indices.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Evaluates every distinct MLE once, then combines the products.
+    pub fn evaluate(&self, values: &[F]) -> F {
+        assert_eq!(
+            values.len() as u32,
+            self.number_of_variables,
+            "Number of values must match number of variables"
+        );
+        let evaluations: Vec<F> = self.mles.iter().map(|mle| mle.evaluate(values)).collect();
+        self.combine(&evaluations)
+    }
+
+    /// Fixes variable `eval_var` to `value` in every constituent MLE, keeping
+    /// the sharing structure.
+    pub fn partial_evaluate(&self, eval_var: usize, value: F) -> Self {
+        assert!(
+            eval_var < self.number_of_variables as usize,
+            "Variable index out of range"
+        );
+        VirtualPolynomial {
+            number_of_variables: self.number_of_variables - 1,
+            mles: self
+                .mles
+                .iter()
+                .map(|mle| {
+                    Arc::new(MultilinearEvalForm {
+                        evaluated_values: mle.partial_evaluate(eval_var, value),
+                    })
+                })
+                .collect(),
+            products: self.products.clone(),
+        }
+    }
+
+    /// Fixes variable 0 to `r`, as a sumcheck prover does every round. MLEs
+    /// held only by this polynomial are folded in place; ones still shared
+    /// elsewhere are replaced by a folded copy.
+    pub fn fix_variable_in_place(&mut self, r: F) {
+        assert!(self.number_of_variables > 0, "No variables left to fix");
+        for mle in self.mles.iter_mut() {
+            if let Some(owned) = Arc::get_mut(mle) {
+                owned.fix_variable_in_place(r);
+            } else {
+                *mle = Arc::new(MultilinearEvalForm {
+                    evaluated_values: mle.partial_evaluate(0, r),
+                });
+            }
+        }
+        self.number_of_variables -= 1;
+    }
+
+    pub fn sum_over_hypercube(&self) -> F {
+        let mut corner = vec![F::zero(); self.mles.len()];
+        (0..1usize << self.number_of_variables)
+            .map(|index| {
+                for (value, mle) in corner.iter_mut().zip(&self.mles) {
+                    *value = mle.evaluated_values[index];
+                }
+                self.combine(&corner)
+            })
+            .sum()
+    }
+
+    fn combine(&self, mle_values: &[F]) -> F {
+        self.products
+            .iter()
+            .map(|(coefficient, indices)| {
+                indices
+                    .iter()
+                    .fold(*coefficient, |acc, &index| acc * mle_values[index])
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{random_mle, random_point};
+    use ark_bn254::Fr;
+    use ark_std::test_rng;
+
+    #[test]
+    fn test_evaluate_sum_of_products() {
+        let mut rng = test_rng();
+        let (f, g, h) = (
+            Arc::new(random_mle(3, &mut rng)),
+            Arc::new(random_mle(3, &mut rng)),
+            Arc::new(random_mle(3, &mut rng)),
+        );
+
+        // 2 f g h + 5 f g - h
+        let mut poly = VirtualPolynomial::new(3);
+        poly.add_product(Fr::from(2), &[f.clone(), g.clone(), h.clone()])
+            .unwrap();
+        poly.add_product(Fr::from(5), &[f.clone(), g.clone()])
+            .unwrap();
+        poly.add_product(-Fr::from(1), std::slice::from_ref(&h))
+            .unwrap();
+
+        assert_eq!(poly.mles().len(), 3);
+        assert_eq!(poly.degree(), 3);
+
+        let point = random_point(3, &mut rng);
+        let (fr, gr, hr) = (f.evaluate(&point), g.evaluate(&point), h.evaluate(&point));
+        assert_eq!(
+            poly.evaluate(&point),
+            Fr::from(2) * fr * gr * hr + Fr::from(5) * fr * gr - hr
+        );
+    }
+
+    #[test]
+    fn test_partial_evaluate_is_consistent() {
+        let mut rng = test_rng();
+        let (f, g) = (
+            Arc::new(random_mle(4, &mut rng)),
+            Arc::new(random_mle(4, &mut rng)),
+        );
+
+        let mut poly = VirtualPolynomial::new(4);
+        poly.add_product(Fr::from(3), &[f.clone(), f.clone(), g.clone()])
+            .unwrap();
+        poly.add_product(Fr::from(7), std::slice::from_ref(&g))
+            .unwrap();
+        assert_eq!(poly.mles().len(), 2);
+
+        let point = random_point(4, &mut rng);
+        let mut folded = poly.clone();
+        for &r in &point {
+            folded = folded.partial_evaluate(0, r);
+        }
+        assert_eq!(folded.number_of_variables(), 0);
+        assert_eq!(folded.evaluate(&[]), poly.evaluate(&point));
+
+        // Binding a middle variable agrees with evaluating at that coordinate
+        let middle = poly.partial_evaluate(2, point[2]);
+        let rest = [point[0], point[1], point[3]];
+        assert_eq!(middle.evaluate(&rest), poly.evaluate(&point));
+    }
+
+    #[test]
+    fn test_fix_variable_in_place() {
+        let mut rng = test_rng();
+        let (f, g) = (
+            Arc::new(random_mle(4, &mut rng)),
+            Arc::new(random_mle(4, &mut rng)),
+        );
+
+        let mut poly = VirtualPolynomial::new(4);
+        poly.add_product(Fr::from(3), &[f.clone(), g.clone()])
+            .unwrap();
+        poly.add_product(Fr::from(7), std::slice::from_ref(&g))
+            .unwrap();
+        let point = random_point(4, &mut rng);
+        let expected = poly.evaluate(&point);
+
+        // The caller still holds g, so it must be copied rather than mutated
+        drop(f);
+        let mut folded = poly.clone();
+        drop(poly);
+        folded.fix_variable_in_place(point[0]);
+        assert_eq!(g.number_of_variables(), 4);
+        assert_eq!(folded.mles()[1].number_of_variables(), 3);
+
+        for &r in &point[1..] {
+            let reference = folded.partial_evaluate(0, r);
+            folded.fix_variable_in_place(r);
+            assert_eq!(folded, reference);
+        }
+        assert_eq!(folded.number_of_variables(), 0);
+        assert_eq!(folded.evaluate(&[]), expected);
+    }
+
+    #[test]
+    fn test_sum_over_hypercube() {
+        let f = Arc::new(MultilinearEvalForm::new(&[
+            Fr::from(1),
+            Fr::from(2),
+            Fr::from(3),
+            Fr::from(4),
+        ]));
+        let g = Arc::new(MultilinearEvalForm::new(&[
+            Fr::from(5),
+            Fr::from(6),
+            Fr::from(7),
+            Fr::from(8),
+        ]));
+
+        let mut poly = VirtualPolynomial::new(2);
+        poly.add_product(Fr::from(1), &[f.clone(), g.clone()])
+            .unwrap();
+        poly.add_product(Fr::from(2), std::slice::from_ref(&f))
+            .unwrap();
+        poly.add_product(Fr::from(1), &[]).unwrap();
+
+        // 70 + 2 * 10 + 4 corners of the constant 1
+        assert_eq!(poly.sum_over_hypercube(), Fr::from(94));
+        assert_eq!(
+            poly.sum_over_hypercube(),
+            f.hadamard(&g).unwrap().sum_over_hypercube() + Fr::from(24)
+        );
+    }
+
+    #[test]
+    fn test_dimension_mismatch() {
+        let mut rng = test_rng();
+        let mut poly = VirtualPolynomial::new(3);
+
+        assert_eq!(
+            poly.add_product(
+                Fr::from(1),
+                &[
+                    Arc::new(random_mle(3, &mut rng)),
+                    Arc::new(random_mle(2, &mut rng))
+                ]
+            ),
+            Err(MultilinearError::DimensionMismatch { left: 3, right: 2 })
+        );
+        assert_eq!(VirtualPolynomial::<Fr>::new(3).degree(), 0);
+    }
+}
@@ -0,0 +1,39 @@
+//! Fixtures shared by the unit tests of every module. Most tests work over
+//! BN254's `Fr`; the generic versions serve the ones over other fields.
+
+use crate::multilinear::{MultilinearEvalForm, SparseMultilinear};
+use ark_bn254::Fr;
+use ark_ff::Field;
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+
+pub(crate) fn random_elements<F: Field>(len: usize, rng: &mut impl Rng) -> Vec<F> {
+    (0..len).map(|_| F::rand(rng)).collect()
+}
+
+/// A table of random values over `num_vars` variables.
+pub(crate) fn random_table<F: Field>(num_vars: u32, rng: &mut impl Rng) -> MultilinearEvalForm<F> {
+    MultilinearEvalForm::new(&random_elements(1 << num_vars, rng))
+}
+
+pub(crate) fn random_values(len: usize, rng: &mut impl Rng) -> Vec<Fr> {
+    random_elements(len, rng)
+}
+
+pub(crate) fn random_mle(num_vars: u32, rng: &mut impl Rng) -> MultilinearEvalForm<Fr> {
+    random_table(num_vars, rng)
+}
+
+/// A random evaluation point, one coordinate per variable.
+pub(crate) fn random_point(num_vars: usize, rng: &mut impl Rng) -> Vec<Fr> {
+    random_elements(num_vars, rng)
+}
+
+/// Six random entries over `number_of_variables` variables; colliding
+/// indices are merged.
+pub(crate) fn random_sparse(number_of_variables: u32, rng: &mut impl Rng) -> SparseMultilinear<Fr> {
+    let entries = (0..6)
+        .map(|_| (rng.gen_range(0..1 << number_of_variables), Fr::rand(rng)))
+        .collect();
+    SparseMultilinear::new(number_of_variables, entries).unwrap()
+}
@@ -1 +1,3 @@
-pub mod univariate;
\ No newline at end of file
+#[cfg(test)]
+pub(crate) mod test_utils;
+pub mod univariate;
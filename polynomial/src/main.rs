@@ -1,34 +1,43 @@
+use ark_bn254::Fr;
 use polynomial::univariate::DensePolynomial;
 
+fn to_field_points(points: &[(u64, u64)]) -> Vec<(Fr, Fr)> {
+    points
+        .iter()
+        .map(|&(x, y)| (Fr::from(x), Fr::from(y)))
+        .collect()
+}
+
 fn main() {
-    let test_cases = vec![
+    let test_cases = [
         // Linear (Degree 1)
-        vec![(0.0, 1.0), (2.0, 5.0)],
-        
+        vec![(0, 1), (2, 5)],
+
         // Quadratic (Degree 2)
-        vec![(0.0, 1.0), (1.0, 2.0), (2.0, 5.0)],
-        
+        vec![(0, 1), (1, 2), (2, 5)],
+
         // Cubic (Degree 3)
-        vec![(0.0, 1.0), (1.0, 3.0), (2.0, 5.0), (3.0, 4.0)],
-        
+        vec![(0, 1), (1, 3), (2, 5), (3, 4)],
+
         // Quartic (Degree 4)
-        vec![(0.0, 1.0), (1.0, 3.0), (2.0, 0.0), (3.0, 4.0), (4.0, 1.0)],
+        vec![(0, 1), (1, 3), (2, 0), (3, 4), (4, 1)],
     ];
 
     for (i, points) in test_cases.iter().enumerate() {
         println!("\nTest Case {} (Degree {})", i + 1, points.len() - 1);
         println!("Points: {:?}", points);
-        
-        let poly = DensePolynomial::interpolate(points);
+
+        let points = to_field_points(points);
+        let poly = DensePolynomial::interpolate(&points);
         println!("Coefficients: {:?}", poly.coefficients);
-        
+
         println!("Verification:");
-        for &(x, y) in points {
-            let evaluated = poly.evaluate(x);
-            println!("f({}) = {:.6} (expected: {})", x, evaluated, y);
+        let xs: Vec<Fr> = points.iter().map(|&(x, _)| x).collect();
+        for (&(x, y), evaluated) in points.iter().zip(poly.evaluate_many(&xs)) {
+            println!("f({}) = {} (expected: {})", x, evaluated, y);
         }
-        
-        let x = points[0].0 + 0.5;
-        println!("Intermediate point f({}) = {:.6}", x, poly.evaluate(x));
+
+        let x = points[0].0 + Fr::from(10);
+        println!("Intermediate point f({}) = {}", x, poly.evaluate(x));
     }
 }
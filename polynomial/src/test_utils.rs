@@ -0,0 +1,35 @@
+//! Fixtures shared by the unit tests of every module.
+
+use crate::univariate::{DensePolynomial, SparsePolynomial};
+use ark_bn254::Fr;
+use ark_ff::PrimeField;
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+
+/// The polynomial with the given small coefficients, constant term first.
+pub(crate) fn poly(coefficients: &[u64]) -> DensePolynomial<Fr> {
+    DensePolynomial::new(coefficients.iter().map(|&c| Fr::from(c)).collect())
+}
+
+/// `X - root`.
+pub(crate) fn linear<F: PrimeField>(root: F) -> DensePolynomial<F> {
+    DensePolynomial::new(vec![-root, F::one()])
+}
+
+pub(crate) fn random_values(n: usize, rng: &mut impl Rng) -> Vec<Fr> {
+    (0..n).map(|_| Fr::rand(rng)).collect()
+}
+
+/// A random polynomial of exactly `degree` with overwhelming probability.
+pub(crate) fn random_poly(degree: usize, rng: &mut impl Rng) -> DensePolynomial<Fr> {
+    DensePolynomial::new(random_values(degree + 1, rng))
+}
+
+/// Five random terms of degree below 40; colliding degrees are merged.
+pub(crate) fn random_sparse(rng: &mut impl Rng) -> SparsePolynomial<Fr> {
+    SparsePolynomial::new(
+        (0..5)
+            .map(|_| (rng.gen_range(0..40), Fr::rand(rng)))
+            .collect(),
+    )
+}
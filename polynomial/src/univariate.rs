@@ -1,25 +1,97 @@
-#[derive(Debug, Clone)]
-pub struct DensePolynomial {
-    pub coefficients: Vec<f64>,
+use ark_ff::PrimeField;
+use std::fmt;
+
+mod arithmetic;
+mod composition;
+mod division;
+mod domain;
+mod evaluations;
+mod factor;
+mod gcd;
+mod serialization;
+mod sparse;
+mod subproduct;
+
+pub use domain::EvaluationDomain;
+pub use evaluations::UnivariateEvals;
+pub use field_codec::SERIALIZATION_VERSION;
+pub use sparse::SparsePolynomial;
+
+/// Minimum degree and number of points for which `evaluate_many` prefers the
+/// O(n log^2 n) subproduct tree over n independent Horner evaluations.
+const MULTI_EVALUATE_THRESHOLD: usize = 256;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolynomialError {
+    DivisionByZero,
+    DomainTooLarge,
+}
+
+impl fmt::Display for PolynomialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PolynomialError::DivisionByZero => write!(f, "division by the zero polynomial"),
+            PolynomialError::DomainTooLarge => {
+                write!(f, "domain size exceeds the two-adicity of the field")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PolynomialError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DensePolynomial<F: PrimeField> {
+    pub coefficients: Vec<F>,
 }
 
-impl DensePolynomial {
-    pub fn new(coefficients: Vec<f64>) -> Self {
+impl<F: PrimeField> DensePolynomial<F> {
+    pub fn new(coefficients: Vec<F>) -> Self {
         let mut coeffs = coefficients;
-        //this makes sure any coefficient that is zero is removed from the vector
-        // coeffs.len() > 1 ensures the vector has more than one coefficient
-        while coeffs.len() > 1 && coeffs.last().map_or(false, |&x| x.abs() < 1e-10) {
+        //this makes sure any coefficient that is zero is removed from the vector,
+        //so the zero polynomial is represented by an empty vector
+        while coeffs.last().is_some_and(|x| x.is_zero()) {
             coeffs.pop();
         }
-        DensePolynomial { coefficients: coeffs }
+        DensePolynomial {
+            coefficients: coeffs,
+        }
+    }
+
+    pub fn zero() -> Self {
+        DensePolynomial {
+            coefficients: Vec::new(),
+        }
     }
 
-    pub fn evaluate(&self, x: f64) -> f64 {
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+
+    /// Horner's rule: one multiplication and one addition per coefficient.
+    pub fn evaluate(&self, x: F) -> F {
         self.coefficients
             .iter()
-            .enumerate()
-            .map(|(i, &coef)| coef * x.powi(i as i32))
-            .sum()
+            .rev()
+            .fold(F::zero(), |acc, &coef| acc * x + coef)
+    }
+
+    /// Evaluates at every point. Large polynomials at many points go through
+    /// the subproduct tree (see `multi_evaluate`), everything else through Horner.
+    pub fn evaluate_many(&self, points: &[F]) -> Vec<F> {
+        if self.coefficients.len().min(points.len()) >= MULTI_EVALUATE_THRESHOLD {
+            self.multi_evaluate(points)
+        } else {
+            points.iter().map(|&x| self.evaluate(x)).collect()
+        }
+    }
+
+    /// Horner evaluation at every point, split across the rayon thread pool.
+    #[cfg(feature = "parallel")]
+    pub fn par_evaluate_many(&self, points: &[F]) -> Vec<F> {
+        use rayon::prelude::*;
+
+        points.par_iter().map(|&x| self.evaluate(x)).collect()
     }
 
     pub fn degree(&self) -> usize {
@@ -30,76 +102,141 @@ impl DensePolynomial {
         }
     }
 
-    pub fn interpolate(points: &[(f64, f64)]) -> Self {
-        if points.is_empty() {
-            return DensePolynomial::new(vec![0.0]);
+    /// Coefficient of the highest power of X, or `None` for the zero polynomial.
+    pub fn leading_coefficient(&self) -> Option<F> {
+        self.coefficients.last().copied()
+    }
+
+    pub fn is_monic(&self) -> bool {
+        self.leading_coefficient() == Some(F::one())
+    }
+
+    /// Divides through by the leading coefficient. The zero polynomial is returned unchanged.
+    pub fn make_monic(&self) -> Self {
+        match self.leading_coefficient() {
+            Some(lead) => self * lead.inverse().unwrap(),
+            None => DensePolynomial::zero(),
         }
+    }
 
-        let n = points.len();
-        let mut result = vec![0.0; n];
+    pub fn interpolate(points: &[(F, F)]) -> Self {
+        let mut result = DensePolynomial::zero();
         //L(X) = y1L(x) + y2L(x)
 
         // For each point, calculate its contribution
-        for i in 0..n {
-            let (xi, yi) = points[i];
-            
+        for (i, &(xi, yi)) in points.iter().enumerate() {
             // Calculate the Lagrange basis polynomial
-            let mut basis = vec![1.0];  // Start with constant term 1
-            let mut factor = 1.0;
+            let mut basis = DensePolynomial::new(vec![F::one()]); // Start with constant term 1
+            let mut factor = F::one();
 
             // Build up the basis polynomial
-            for j in 0..n {
+            for (j, &(xj, _)) in points.iter().enumerate() {
                 if i != j {
-                    let (xj, _) = points[j];
                     factor *= xi - xj;
-                    
+
                     // Multiply by (x - xj)
-                    let mut new_basis = vec![0.0; basis.len() + 1];
-                    for k in 0..basis.len() {
-                        new_basis[k + 1] += basis[k];         // x term
-                        new_basis[k] -= basis[k] * xj;        // constant term
-                    }
-                    basis = new_basis;
+                    basis = &basis * &DensePolynomial::new(vec![-xj, F::one()]);
                 }
             }
 
             // Scale the basis polynomial by yi / factor
-            let scale = yi / factor;
-            for k in 0..basis.len() {
-                result[k] += basis[k] * scale;
-            }
-        }
-
-        // Clean up any very small coefficients that might be numerical noise
-        for coef in result.iter_mut() {
-            if coef.abs() < 1e-10 {
-                *coef = 0.0;
-            }
+            let scale = yi
+                * factor
+                    .inverse()
+                    .expect("interpolation points must have distinct x-coordinates");
+            result = &result + &(&basis * scale);
         }
 
-        DensePolynomial::new(result)
+        result
     }
 }
 
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ark_bn254::Fr;
+    use ark_ff::Field;
+
+    fn points(values: &[(u64, u64)]) -> Vec<(Fr, Fr)> {
+        values
+            .iter()
+            .map(|&(x, y)| (Fr::from(x), Fr::from(y)))
+            .collect()
+    }
 
     #[test]
     fn test_linear_interpolation() {
-        let points = vec![(0.0, 1.0), (2.0, 5.0)];
+        let points = points(&[(0, 1), (2, 5)]);
         let poly = DensePolynomial::interpolate(&points);
-        assert!((poly.evaluate(0.0) - 1.0).abs() < 1e-10);
-        assert!((poly.evaluate(2.0) - 5.0).abs() < 1e-10);
+        assert_eq!(poly.evaluate(Fr::from(0)), Fr::from(1));
+        assert_eq!(poly.evaluate(Fr::from(2)), Fr::from(5));
+        assert_eq!(poly.degree(), 1);
     }
 
     #[test]
     fn test_quadratic_interpolation() {
-        let points = vec![(0.0, 1.0), (1.0, 2.0), (2.0, 5.0)];
+        let points = points(&[(0, 1), (1, 2), (2, 5)]);
+        let poly = DensePolynomial::interpolate(&points);
+        for &(x, y) in &points {
+            assert_eq!(poly.evaluate(x), y);
+        }
+        // 1 + x^2
+        assert_eq!(
+            poly,
+            DensePolynomial::new(vec![Fr::from(1), Fr::from(0), Fr::from(1)])
+        );
+    }
+
+    #[test]
+    fn test_new_trims_trailing_zeros() {
+        let poly = DensePolynomial::new(vec![Fr::from(3), Fr::from(2), Fr::from(0), Fr::from(0)]);
+        assert_eq!(poly.coefficients, vec![Fr::from(3), Fr::from(2)]);
+        assert_eq!(poly.degree(), 1);
+
+        let zero = DensePolynomial::new(vec![Fr::from(0)]);
+        assert!(zero.is_zero());
+        assert_eq!(zero, DensePolynomial::zero());
+    }
+
+    #[test]
+    fn test_interpolation_is_exact_for_large_values() {
+        // -1 in the field; f64 interpolation would lose all precision here
+        let minus_one = -Fr::from(1);
+        let points = vec![
+            (Fr::from(1), minus_one),
+            (Fr::from(2), Fr::from(u64::MAX)),
+            (Fr::from(3), minus_one),
+        ];
         let poly = DensePolynomial::interpolate(&points);
         for &(x, y) in &points {
-            assert!((poly.evaluate(x) - y).abs() < 1e-10);
+            assert_eq!(poly.evaluate(x), y);
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_horner_matches_power_sum() {
+        let poly = DensePolynomial::new(vec![Fr::from(3), Fr::from(0), Fr::from(5), Fr::from(7)]);
+        let x = Fr::from(11);
+        let expected: Fr = poly
+            .coefficients
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| c * x.pow([i as u64]))
+            .sum();
+        assert_eq!(poly.evaluate(x), expected);
+        assert_eq!(DensePolynomial::<Fr>::zero().evaluate(x), Fr::from(0));
+    }
+
+    #[test]
+    fn test_evaluate_many() {
+        let poly = DensePolynomial::new((1..=300).map(Fr::from).collect());
+        for n in [0, 3, 300] {
+            let points: Vec<Fr> = (0..n).map(|i| Fr::from(i * i + 1)).collect();
+            let expected: Vec<Fr> = points.iter().map(|&x| poly.evaluate(x)).collect();
+            assert_eq!(poly.evaluate_many(&points), expected);
+
+            #[cfg(feature = "parallel")]
+            assert_eq!(poly.par_evaluate_many(&points), expected);
+        }
+    }
+}
@@ -0,0 +1,206 @@
+use super::domain::ntt_mul;
+use super::DensePolynomial;
+use ark_ff::PrimeField;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// Below this many coefficients in the smaller factor, schoolbook
+/// multiplication beats the three NTTs.
+const NTT_MUL_THRESHOLD: usize = 64;
+
+// The by-reference impls do the work; the owned impls forward to them.
+// Every result goes through `DensePolynomial::new` so trailing zeros are
+// always trimmed the same way.
+
+impl<F: PrimeField> Add<&DensePolynomial<F>> for &DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn add(self, rhs: &DensePolynomial<F>) -> DensePolynomial<F> {
+        let (longer, shorter) = if self.coefficients.len() >= rhs.coefficients.len() {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+
+        let mut result = longer.coefficients.clone();
+        for (r, &c) in result.iter_mut().zip(shorter.coefficients.iter()) {
+            *r += c;
+        }
+        DensePolynomial::new(result)
+    }
+}
+
+impl<F: PrimeField> Add for DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn add(self, rhs: DensePolynomial<F>) -> DensePolynomial<F> {
+        &self + &rhs
+    }
+}
+
+impl<F: PrimeField> Neg for &DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn neg(self) -> DensePolynomial<F> {
+        DensePolynomial::new(self.coefficients.iter().map(|&c| -c).collect())
+    }
+}
+
+impl<F: PrimeField> Neg for DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn neg(self) -> DensePolynomial<F> {
+        -&self
+    }
+}
+
+impl<F: PrimeField> Sub<&DensePolynomial<F>> for &DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn sub(self, rhs: &DensePolynomial<F>) -> DensePolynomial<F> {
+        let len = self.coefficients.len().max(rhs.coefficients.len());
+        let mut result = self.coefficients.clone();
+        result.resize(len, F::zero());
+        for (r, &c) in result.iter_mut().zip(rhs.coefficients.iter()) {
+            *r -= c;
+        }
+        DensePolynomial::new(result)
+    }
+}
+
+impl<F: PrimeField> Sub for DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn sub(self, rhs: DensePolynomial<F>) -> DensePolynomial<F> {
+        &self - &rhs
+    }
+}
+
+impl<F: PrimeField> Mul<&DensePolynomial<F>> for &DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn mul(self, rhs: &DensePolynomial<F>) -> DensePolynomial<F> {
+        if self.is_zero() || rhs.is_zero() {
+            return DensePolynomial::zero();
+        }
+
+        if self.coefficients.len().min(rhs.coefficients.len()) >= NTT_MUL_THRESHOLD {
+            if let Some(result) = ntt_mul(&self.coefficients, &rhs.coefficients) {
+                return DensePolynomial::new(result);
+            }
+        }
+
+        let mut result = vec![F::zero(); self.coefficients.len() + rhs.coefficients.len() - 1];
+        for (i, &a) in self.coefficients.iter().enumerate() {
+            for (j, &b) in rhs.coefficients.iter().enumerate() {
+                result[i + j] += a * b;
+            }
+        }
+        DensePolynomial::new(result)
+    }
+}
+
+impl<F: PrimeField> Mul for DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn mul(self, rhs: DensePolynomial<F>) -> DensePolynomial<F> {
+        &self * &rhs
+    }
+}
+
+// Scalar multiplication
+impl<F: PrimeField> Mul<F> for &DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn mul(self, scalar: F) -> DensePolynomial<F> {
+        DensePolynomial::new(self.coefficients.iter().map(|&c| c * scalar).collect())
+    }
+}
+
+impl<F: PrimeField> Mul<F> for DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn mul(self, scalar: F) -> DensePolynomial<F> {
+        &self * scalar
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{poly, random_poly};
+    use ark_bn254::Fr;
+    use ark_std::{test_rng, UniformRand};
+
+    #[test]
+    fn test_add_and_sub() {
+        let p = poly(&[1, 2, 3]);
+        let q = poly(&[4, 5]);
+        assert_eq!(&p + &q, poly(&[5, 7, 3]));
+        assert_eq!(&q + &p, poly(&[5, 7, 3]));
+        assert_eq!(p.clone() - q.clone(), &p + &(-&q));
+    }
+
+    #[test]
+    fn test_cancellation_normalizes() {
+        let p = poly(&[1, 2, 3]);
+        let q = poly(&[0, 0, 3]);
+        let diff = &p - &q;
+        assert_eq!(diff.coefficients, vec![Fr::from(1), Fr::from(2)]);
+        assert_eq!(diff.degree(), 1);
+
+        assert!((&p - &p).is_zero());
+        assert!((p.clone() + (-p)).is_zero());
+    }
+
+    #[test]
+    fn test_mul() {
+        // (1 + x)(1 - x) = 1 - x^2
+        let p = poly(&[1, 1]);
+        let q = DensePolynomial::new(vec![Fr::from(1), -Fr::from(1)]);
+        assert_eq!(
+            &p * &q,
+            DensePolynomial::new(vec![Fr::from(1), Fr::from(0), -Fr::from(1)])
+        );
+        assert!((p * DensePolynomial::zero()).is_zero());
+    }
+
+    #[test]
+    fn test_scalar_mul() {
+        let p = poly(&[1, 2, 3]);
+        assert_eq!(&p * Fr::from(2), poly(&[2, 4, 6]));
+        assert!((p * Fr::from(0)).is_zero());
+    }
+
+    #[test]
+    fn test_mul_distributes_over_add() {
+        let mut rng = test_rng();
+        for degree in 0..8 {
+            let p = random_poly(degree, &mut rng);
+            let q = random_poly(degree + 1, &mut rng);
+            let r = random_poly(2 * degree, &mut rng);
+
+            assert_eq!(&p * &(&q + &r), &(&p * &q) + &(&p * &r));
+            assert_eq!(
+                &(&p + &q) * Fr::from(7),
+                &(&p * Fr::from(7)) + &(&q * Fr::from(7))
+            );
+        }
+    }
+
+    #[test]
+    fn test_mul_matches_pointwise_product() {
+        let mut rng = test_rng();
+        for degree in 0..8 {
+            let p = random_poly(degree, &mut rng);
+            let q = random_poly(degree + 3, &mut rng);
+            let product = &p * &q;
+            assert_eq!(product.degree(), p.degree() + q.degree());
+
+            for _ in 0..4 {
+                let x = Fr::rand(&mut rng);
+                assert_eq!(product.evaluate(x), p.evaluate(x) * q.evaluate(x));
+                assert_eq!((&p - &q).evaluate(x), p.evaluate(x) - q.evaluate(x));
+            }
+        }
+    }
+}
@@ -0,0 +1,149 @@
+use super::DensePolynomial;
+use ark_ff::PrimeField;
+
+impl<F: PrimeField> DensePolynomial<F> {
+    /// Formal derivative `sum i * a_i X^(i-1)`.
+    pub fn derivative(&self) -> DensePolynomial<F> {
+        DensePolynomial::new(
+            self.coefficients
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, &c)| c * F::from(i as u64))
+                .collect(),
+        )
+    }
+
+    /// `self(other(X))`, by Horner's rule over polynomials.
+    pub fn compose(&self, other: &DensePolynomial<F>) -> DensePolynomial<F> {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(DensePolynomial::zero(), |acc, &c| {
+                &(&acc * other) + &DensePolynomial::new(vec![c])
+            })
+    }
+
+    /// Taylor shift `self(X + a)` in O(n^2) field operations, without the
+    /// factorials that would break in small characteristic.
+    pub fn shift(&self, a: F) -> DensePolynomial<F> {
+        let mut coefficients = self.coefficients.clone();
+        let n = coefficients.len();
+        // Each pass is a synthetic division by (X - (-a)) on the upper part
+        for i in 0..n {
+            for j in (i..n - 1).rev() {
+                let carry = a * coefficients[j + 1];
+                coefficients[j] += carry;
+            }
+        }
+        DensePolynomial::new(coefficients)
+    }
+
+    /// `self(c X)`: the i-th coefficient is multiplied by `c^i`.
+    pub fn scale_variable(&self, c: F) -> DensePolynomial<F> {
+        let mut power = F::one();
+        let mut coefficients = Vec::with_capacity(self.coefficients.len());
+        for &coefficient in &self.coefficients {
+            coefficients.push(coefficient * power);
+            power *= c;
+        }
+        DensePolynomial::new(coefficients)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{poly, random_poly};
+    use ark_bn254::Fr;
+    use ark_ff::Field;
+    use ark_std::{test_rng, UniformRand};
+
+    #[test]
+    fn test_derivative() {
+        // d/dX (1 + 2X + 3X^2) = 2 + 6X
+        assert_eq!(poly(&[1, 2, 3]).derivative(), poly(&[2, 6]));
+        assert!(poly(&[5]).derivative().is_zero());
+        assert!(DensePolynomial::<Fr>::zero().derivative().is_zero());
+    }
+
+    #[test]
+    fn test_derivative_product_rule() {
+        let mut rng = test_rng();
+        for degree in 0..6 {
+            let p = random_poly(degree, &mut rng);
+            let q = random_poly(degree + 2, &mut rng);
+            assert_eq!(
+                (&p * &q).derivative(),
+                &(&p.derivative() * &q) + &(&p * &q.derivative())
+            );
+        }
+    }
+
+    #[test]
+    fn test_double_root_vanishes_in_derivative() {
+        let mut rng = test_rng();
+        let r = Fr::rand(&mut rng);
+        let linear = DensePolynomial::new(vec![-r, Fr::from(1)]);
+        let p = &(&linear * &linear) * &random_poly(3, &mut rng);
+
+        assert_eq!(p.evaluate(r), Fr::from(0));
+        assert_eq!(p.derivative().evaluate(r), Fr::from(0));
+        assert_ne!(p.derivative().derivative().evaluate(r), Fr::from(0));
+    }
+
+    #[test]
+    fn test_compose() {
+        // p(X) = X^2 + 1, q(X) = X + 1 => p(q(X)) = X^2 + 2X + 2
+        assert_eq!(poly(&[1, 0, 1]).compose(&poly(&[1, 1])), poly(&[2, 2, 1]));
+
+        let mut rng = test_rng();
+        let p = random_poly(5, &mut rng);
+        let q = random_poly(3, &mut rng);
+        let composed = p.compose(&q);
+        assert_eq!(composed.degree(), 15);
+        for _ in 0..4 {
+            let x = Fr::rand(&mut rng);
+            assert_eq!(composed.evaluate(x), p.evaluate(q.evaluate(x)));
+        }
+        assert!(DensePolynomial::zero().compose(&q).is_zero());
+    }
+
+    #[test]
+    fn test_shift() {
+        let mut rng = test_rng();
+        let p = random_poly(7, &mut rng);
+        let a = Fr::rand(&mut rng);
+
+        let shifted = p.shift(a);
+        assert_eq!(
+            shifted,
+            p.compose(&DensePolynomial::new(vec![a, Fr::from(1)]))
+        );
+        assert_eq!(shifted.shift(-a), p);
+
+        let x = Fr::rand(&mut rng);
+        assert_eq!(shifted.evaluate(x), p.evaluate(x + a));
+    }
+
+    #[test]
+    fn test_scale_variable() {
+        let mut rng = test_rng();
+        let p = random_poly(7, &mut rng);
+        let c = Fr::rand(&mut rng);
+
+        let scaled = p.scale_variable(c);
+        assert_eq!(
+            scaled,
+            p.compose(&DensePolynomial::new(vec![Fr::from(0), c]))
+        );
+        assert_eq!(scaled.scale_variable(c.inverse().unwrap()), p);
+
+        let x = Fr::rand(&mut rng);
+        assert_eq!(scaled.evaluate(x), p.evaluate(c * x));
+        assert_eq!(
+            p.scale_variable(Fr::from(0)),
+            DensePolynomial::new(vec![p.coefficients[0]])
+        );
+    }
+}
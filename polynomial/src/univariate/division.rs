@@ -0,0 +1,300 @@
+use super::{DensePolynomial, PolynomialError};
+use ark_ff::PrimeField;
+use std::ops::{Div, Rem};
+
+/// Below this size schoolbook division is cheaper than the Newton iteration.
+const FAST_DIVISION_THRESHOLD: usize = 64;
+
+impl<F: PrimeField> DensePolynomial<F> {
+    /// Long division: returns `(q, r)` with `self = q * divisor + r` and
+    /// `deg(r) < deg(divisor)`.
+    ///
+    /// Large divisions go through a Newton-iteration inverse of the reversed
+    /// divisor, so they cost a constant number of (NTT) multiplications.
+    pub fn div_rem(
+        &self,
+        divisor: &DensePolynomial<F>,
+    ) -> Result<(DensePolynomial<F>, DensePolynomial<F>), PolynomialError> {
+        if divisor.is_zero() {
+            return Err(PolynomialError::DivisionByZero);
+        }
+
+        if self.coefficients.len() < divisor.coefficients.len() {
+            return Ok((DensePolynomial::zero(), self.clone()));
+        }
+
+        let quotient_len = self.coefficients.len() - divisor.coefficients.len() + 1;
+        if quotient_len.min(divisor.coefficients.len()) >= FAST_DIVISION_THRESHOLD {
+            Ok(newton_div_rem(self, divisor))
+        } else {
+            Ok(schoolbook_div_rem(self, divisor))
+        }
+    }
+
+    /// Synthetic division by `(X - a)`: returns the quotient and the remainder,
+    /// which is `self.evaluate(a)`.
+    pub fn divide_by_linear(&self, a: F) -> (DensePolynomial<F>, F) {
+        if self.is_zero() {
+            return (DensePolynomial::zero(), F::zero());
+        }
+
+        let n = self.coefficients.len();
+        let mut quotient = vec![F::zero(); n - 1];
+        let mut carry = F::zero();
+        for i in (0..n).rev() {
+            carry = self.coefficients[i] + carry * a;
+            if i > 0 {
+                quotient[i - 1] = carry;
+            }
+        }
+
+        (DensePolynomial::new(quotient), carry)
+    }
+
+    /// Division by the vanishing polynomial `X^n - 1` of the order-`n` subgroup,
+    /// in O(deg) time using `X^n = 1` modulo the divisor.
+    pub fn divide_by_vanishing_poly(
+        &self,
+        n: usize,
+    ) -> Result<(DensePolynomial<F>, DensePolynomial<F>), PolynomialError> {
+        if n == 0 {
+            // X^0 - 1 is the zero polynomial
+            return Err(PolynomialError::DivisionByZero);
+        }
+
+        if self.coefficients.len() <= n {
+            return Ok((DensePolynomial::zero(), self.clone()));
+        }
+
+        let mut remainder = self.coefficients.clone();
+        let mut quotient = vec![F::zero(); remainder.len() - n];
+        for i in (n..remainder.len()).rev() {
+            let c = remainder[i];
+            quotient[i - n] = c;
+            remainder[i - n] += c;
+        }
+
+        remainder.truncate(n);
+        Ok((
+            DensePolynomial::new(quotient),
+            DensePolynomial::new(remainder),
+        ))
+    }
+
+    /// Returns true when `divisor` divides `self` exactly.
+    pub fn is_divisible_by(&self, divisor: &DensePolynomial<F>) -> Result<bool, PolynomialError> {
+        let (_, remainder) = self.div_rem(divisor)?;
+        Ok(remainder.is_zero())
+    }
+}
+
+impl<F: PrimeField> Div<&DensePolynomial<F>> for &DensePolynomial<F> {
+    type Output = Result<DensePolynomial<F>, PolynomialError>;
+
+    fn div(self, rhs: &DensePolynomial<F>) -> Self::Output {
+        self.div_rem(rhs).map(|(quotient, _)| quotient)
+    }
+}
+
+impl<F: PrimeField> Div for DensePolynomial<F> {
+    type Output = Result<DensePolynomial<F>, PolynomialError>;
+
+    fn div(self, rhs: DensePolynomial<F>) -> Self::Output {
+        &self / &rhs
+    }
+}
+
+impl<F: PrimeField> Rem<&DensePolynomial<F>> for &DensePolynomial<F> {
+    type Output = Result<DensePolynomial<F>, PolynomialError>;
+
+    fn rem(self, rhs: &DensePolynomial<F>) -> Self::Output {
+        self.div_rem(rhs).map(|(_, remainder)| remainder)
+    }
+}
+
+impl<F: PrimeField> Rem for DensePolynomial<F> {
+    type Output = Result<DensePolynomial<F>, PolynomialError>;
+
+    fn rem(self, rhs: DensePolynomial<F>) -> Self::Output {
+        &self % &rhs
+    }
+}
+
+// Both helpers expect a non-zero divisor with `deg(divisor) <= deg(dividend)`.
+fn schoolbook_div_rem<F: PrimeField>(
+    dividend: &DensePolynomial<F>,
+    divisor: &DensePolynomial<F>,
+) -> (DensePolynomial<F>, DensePolynomial<F>) {
+    let lead_inv = divisor.coefficients.last().unwrap().inverse().unwrap();
+    let divisor_len = divisor.coefficients.len();
+    let mut remainder = dividend.coefficients.clone();
+    let mut quotient = vec![F::zero(); remainder.len() - divisor_len + 1];
+
+    // Cancel the top coefficient of the remainder one degree at a time
+    for i in (0..quotient.len()).rev() {
+        let factor = remainder[i + divisor_len - 1] * lead_inv;
+        quotient[i] = factor;
+        for (j, &d) in divisor.coefficients.iter().enumerate() {
+            remainder[i + j] -= factor * d;
+        }
+    }
+
+    remainder.truncate(divisor_len - 1);
+    (
+        DensePolynomial::new(quotient),
+        DensePolynomial::new(remainder),
+    )
+}
+
+// With rev(p)(X) = X^deg(p) p(1/X), the quotient satisfies
+// rev(q) = rev(a) * rev(b)^-1 mod X^(deg a - deg b + 1).
+fn newton_div_rem<F: PrimeField>(
+    dividend: &DensePolynomial<F>,
+    divisor: &DensePolynomial<F>,
+) -> (DensePolynomial<F>, DensePolynomial<F>) {
+    let quotient_len = dividend.coefficients.len() - divisor.coefficients.len() + 1;
+
+    let reversed_divisor: Vec<F> = divisor.coefficients.iter().rev().copied().collect();
+    let reversed_dividend: Vec<F> = dividend
+        .coefficients
+        .iter()
+        .rev()
+        .take(quotient_len)
+        .copied()
+        .collect();
+
+    let inverse = inverse_mod_x_pow(&reversed_divisor, quotient_len);
+    let mut quotient = truncated(
+        &DensePolynomial::new(reversed_dividend) * &DensePolynomial::new(inverse),
+        quotient_len,
+    );
+    quotient.reverse();
+
+    let quotient = DensePolynomial::new(quotient);
+    let remainder = dividend - &(&quotient * divisor);
+    (quotient, remainder)
+}
+
+/// Power series inverse of `f` modulo `X^n`, doubling the precision each step
+/// with `g <- g (2 - f g)`. Requires `f[0] != 0`.
+fn inverse_mod_x_pow<F: PrimeField>(f: &[F], n: usize) -> Vec<F> {
+    let mut inverse = vec![f[0].inverse().unwrap()];
+    let mut precision = 1;
+    while precision < n {
+        precision = (2 * precision).min(n);
+        let f_low = DensePolynomial::new(f[..precision.min(f.len())].to_vec());
+        let g = DensePolynomial::new(inverse);
+
+        let mut correction = truncated(&f_low * &g, precision);
+        for c in correction.iter_mut() {
+            *c = -*c;
+        }
+        correction[0] += F::from(2u64);
+
+        inverse = truncated(&g * &DensePolynomial::new(correction), precision);
+    }
+    inverse
+}
+
+/// The first `len` coefficients, zero-padded.
+fn truncated<F: PrimeField>(poly: DensePolynomial<F>, len: usize) -> Vec<F> {
+    let mut coefficients = poly.coefficients;
+    coefficients.resize(len, F::zero());
+    coefficients
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{poly, random_poly};
+    use ark_bn254::Fr;
+    use ark_std::{test_rng, UniformRand};
+
+    #[test]
+    fn test_exact_division() {
+        // (x + 1)(x + 2) = x^2 + 3x + 2
+        let p = poly(&[2, 3, 1]);
+        let d = poly(&[1, 1]);
+        let (q, r) = p.div_rem(&d).unwrap();
+        assert_eq!(q, poly(&[2, 1]));
+        assert!(r.is_zero());
+        assert!(p.is_divisible_by(&d).unwrap());
+        assert_eq!((&p / &d).unwrap(), q);
+        assert!((p % d).unwrap().is_zero());
+    }
+
+    #[test]
+    fn test_div_rem_identity() {
+        let mut rng = test_rng();
+        for (n, m) in [(0, 0), (3, 1), (7, 3), (10, 10), (12, 5), (2, 6)] {
+            let p = random_poly(n, &mut rng);
+            let d = random_poly(m, &mut rng);
+            let (q, r) = p.div_rem(&d).unwrap();
+
+            assert_eq!(&(&q * &d) + &r, p);
+            assert!(r.is_zero() || r.degree() < d.degree());
+        }
+    }
+
+    #[test]
+    fn test_division_by_zero_is_an_error() {
+        let p = poly(&[1, 2, 3]);
+        let zero = DensePolynomial::zero();
+        assert_eq!(p.div_rem(&zero), Err(PolynomialError::DivisionByZero));
+        assert_eq!(&p / &zero, Err(PolynomialError::DivisionByZero));
+        assert_eq!(&p % &zero, Err(PolynomialError::DivisionByZero));
+        assert_eq!(
+            p.divide_by_vanishing_poly(0),
+            Err(PolynomialError::DivisionByZero)
+        );
+    }
+
+    #[test]
+    fn test_divide_by_linear() {
+        let mut rng = test_rng();
+        let p = random_poly(9, &mut rng);
+        let a = Fr::rand(&mut rng);
+
+        let (q, r) = p.divide_by_linear(a);
+        assert_eq!(r, p.evaluate(a));
+        assert_eq!(
+            (q.clone(), DensePolynomial::new(vec![r])),
+            p.div_rem(&DensePolynomial::new(vec![-a, Fr::from(1)]))
+                .unwrap()
+        );
+
+        // p(X) - p(a) vanishes at a
+        let shifted = &p - &DensePolynomial::new(vec![r]);
+        assert_eq!(shifted.divide_by_linear(a), (q, Fr::from(0)));
+    }
+
+    #[test]
+    fn test_divide_by_vanishing_poly() {
+        let mut rng = test_rng();
+        for (degree, n) in [(15, 4), (8, 8), (3, 8), (20, 1)] {
+            let p = random_poly(degree, &mut rng);
+            let mut vanishing = vec![Fr::from(0); n + 1];
+            vanishing[0] = -Fr::from(1);
+            vanishing[n] = Fr::from(1);
+            let vanishing = DensePolynomial::new(vanishing);
+
+            assert_eq!(
+                p.divide_by_vanishing_poly(n).unwrap(),
+                p.div_rem(&vanishing).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_newton_division_matches_schoolbook() {
+        let mut rng = test_rng();
+        for (n, m) in [(200, 70), (300, 150), (129, 64)] {
+            let p = random_poly(n, &mut rng);
+            let d = random_poly(m, &mut rng);
+            let (q, r) = newton_div_rem(&p, &d);
+
+            assert_eq!((q.clone(), r.clone()), schoolbook_div_rem(&p, &d));
+            assert_eq!(&(&q * &d) + &r, p);
+        }
+    }
+}
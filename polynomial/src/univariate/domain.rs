@@ -0,0 +1,342 @@
+use super::{DensePolynomial, PolynomialError};
+use ark_ff::PrimeField;
+
+/// A radix-2 multiplicative subgroup `H = {1, w, w^2, ..., w^(n-1)}` of the
+/// field, used for NTT-based evaluation and interpolation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EvaluationDomain<F: PrimeField> {
+    size: usize,
+    group_gen: F,
+    group_gen_inv: F,
+    size_inv: F,
+    // Generator of the multiplicative group, used to shift H to the coset gH
+    offset: F,
+    offset_inv: F,
+}
+
+impl<F: PrimeField> EvaluationDomain<F> {
+    /// Smallest power-of-two domain with at least `size` elements.
+    pub fn new(size: usize) -> Result<Self, PolynomialError> {
+        let size = size.max(1).next_power_of_two();
+        if size.trailing_zeros() > F::TWO_ADICITY {
+            return Err(PolynomialError::DomainTooLarge);
+        }
+
+        let group_gen = F::get_root_of_unity(size as u64).ok_or(PolynomialError::DomainTooLarge)?;
+        let offset = F::GENERATOR;
+
+        Ok(EvaluationDomain {
+            size,
+            group_gen,
+            group_gen_inv: group_gen.inverse().unwrap(),
+            size_inv: F::from(size as u64).inverse().unwrap(),
+            offset,
+            offset_inv: offset.inverse().unwrap(),
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn group_gen(&self) -> F {
+        self.group_gen
+    }
+
+    pub fn coset_offset(&self) -> F {
+        self.offset
+    }
+
+    pub fn element(&self, i: usize) -> F {
+        self.group_gen.pow([i as u64])
+    }
+
+    pub fn elements(&self) -> Vec<F> {
+        powers(self.group_gen, self.size)
+    }
+
+    /// `X^n - 1`, which vanishes on every element of the domain.
+    pub fn vanishing_polynomial(&self) -> DensePolynomial<F> {
+        let mut coefficients = vec![F::zero(); self.size + 1];
+        coefficients[0] = -F::one();
+        coefficients[self.size] = F::one();
+        DensePolynomial::new(coefficients)
+    }
+
+    /// Evaluations of the polynomial with the given coefficients on `H`. Any
+    /// degree is accepted: the coefficients are first reduced mod `X^n - 1`.
+    pub fn fft(&self, coefficients: &[F]) -> Vec<F> {
+        let mut values = self.reduce(coefficients);
+        ntt_in_place(&mut values, self.group_gen);
+        values
+    }
+
+    /// Coefficients of the unique polynomial of degree `< n` taking `evaluations` on `H`.
+    /// Missing evaluations are taken to be zero.
+    ///
+    /// # Panics
+    ///
+    /// If there are more evaluations than points in the domain.
+    pub fn ifft(&self, evaluations: &[F]) -> Vec<F> {
+        let mut values = self.pad(evaluations);
+        ntt_in_place(&mut values, self.group_gen_inv);
+        for value in values.iter_mut() {
+            *value *= self.size_inv;
+        }
+        values
+    }
+
+    /// Evaluations on the coset `gH`, where `g` is [`Self::coset_offset`].
+    /// Like [`Self::fft`], any degree is accepted.
+    pub fn coset_fft(&self, coefficients: &[F]) -> Vec<F> {
+        // p(gX) has the same values on H as p(gX) mod X^n - 1, so shift first
+        let mut shifted = coefficients.to_vec();
+        scale_by_powers(&mut shifted, self.offset);
+        let mut values = self.reduce(&shifted);
+        ntt_in_place(&mut values, self.group_gen);
+        values
+    }
+
+    /// Inverse of [`Self::coset_fft`].
+    ///
+    /// # Panics
+    ///
+    /// If there are more evaluations than points in the domain.
+    pub fn coset_ifft(&self, evaluations: &[F]) -> Vec<F> {
+        let mut coefficients = self.ifft(evaluations);
+        scale_by_powers(&mut coefficients, self.offset_inv);
+        coefficients
+    }
+
+    /// Folds coefficient `i` into slot `i mod n`, i.e. reduces mod `X^n - 1`,
+    /// which leaves the values on `H` unchanged.
+    fn reduce(&self, coefficients: &[F]) -> Vec<F> {
+        let mut reduced = vec![F::zero(); self.size];
+        for (i, coefficient) in coefficients.iter().enumerate() {
+            reduced[i % self.size] += coefficient;
+        }
+        reduced
+    }
+
+    fn pad(&self, values: &[F]) -> Vec<F> {
+        assert!(
+            values.len() <= self.size,
+            "Input length must not exceed the domain size"
+        );
+        let mut padded = values.to_vec();
+        padded.resize(self.size, F::zero());
+        padded
+    }
+}
+
+impl<F: PrimeField> DensePolynomial<F> {
+    /// Interpolates from evaluations on `domain` in O(n log n).
+    ///
+    /// # Panics
+    ///
+    /// If there are more evaluations than points in `domain`.
+    pub fn interpolate_on_domain(domain: &EvaluationDomain<F>, evaluations: &[F]) -> Self {
+        DensePolynomial::new(domain.ifft(evaluations))
+    }
+
+    /// Evaluates the polynomial on every element of `domain` in O(n log n).
+    pub fn evaluate_over_domain(&self, domain: &EvaluationDomain<F>) -> Vec<F> {
+        domain.fft(&self.coefficients)
+    }
+}
+
+/// Product of two non-zero polynomials via pointwise multiplication on a
+/// domain large enough to hold the result.
+pub(super) fn ntt_mul<F: PrimeField>(a: &[F], b: &[F]) -> Option<Vec<F>> {
+    let result_len = a.len() + b.len() - 1;
+    let domain = EvaluationDomain::new(result_len).ok()?;
+
+    let a_evals = domain.fft(a);
+    let b_evals = domain.fft(b);
+    let product: Vec<F> = a_evals
+        .iter()
+        .zip(b_evals.iter())
+        .map(|(x, y)| *x * y)
+        .collect();
+
+    let mut coefficients = domain.ifft(&product);
+    coefficients.truncate(result_len);
+    Some(coefficients)
+}
+
+fn powers<F: PrimeField>(base: F, n: usize) -> Vec<F> {
+    let mut result = Vec::with_capacity(n);
+    let mut current = F::one();
+    for _ in 0..n {
+        result.push(current);
+        current *= base;
+    }
+    result
+}
+
+fn scale_by_powers<F: PrimeField>(values: &mut [F], base: F) {
+    let mut current = F::one();
+    for value in values.iter_mut() {
+        *value *= current;
+        current *= base;
+    }
+}
+
+fn bit_reverse_permute<F>(values: &mut [F]) {
+    let n = values.len();
+    if n <= 1 {
+        return;
+    }
+    let shift = usize::BITS - n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> shift;
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+}
+
+// Iterative Cooley-Tukey: bit-reverse the input, then merge butterflies of
+// growing size. `omega` must be a primitive `values.len()`-th root of unity.
+fn ntt_in_place<F: PrimeField>(values: &mut [F], omega: F) {
+    let n = values.len();
+    if n <= 1 {
+        return;
+    }
+    bit_reverse_permute(values);
+
+    let mut half = 1;
+    while half < n {
+        let twiddles = powers(omega.pow([(n / (2 * half)) as u64]), half);
+        for chunk in values.chunks_mut(2 * half) {
+            let (low, high) = chunk.split_at_mut(half);
+            for ((u, v), w) in low.iter_mut().zip(high.iter_mut()).zip(twiddles.iter()) {
+                let t = *v * w;
+                *v = *u - t;
+                *u += t;
+            }
+        }
+        half *= 2;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::random_values;
+    use ark_bn254::Fr;
+    use ark_ff::Field;
+    use ark_std::test_rng;
+
+    #[test]
+    fn test_domain_size_rounds_up() {
+        let domain = EvaluationDomain::<Fr>::new(5).unwrap();
+        assert_eq!(domain.size(), 8);
+        assert_eq!(domain.group_gen().pow([8]), Fr::from(1));
+        assert_ne!(domain.group_gen().pow([4]), Fr::from(1));
+
+        let vanishing = domain.vanishing_polynomial();
+        for x in domain.elements() {
+            assert_eq!(vanishing.evaluate(x), Fr::from(0));
+        }
+    }
+
+    #[test]
+    fn test_domain_too_large() {
+        // BN254's scalar field has two-adicity 28
+        assert_eq!(
+            EvaluationDomain::<Fr>::new(1 << 29),
+            Err(PolynomialError::DomainTooLarge)
+        );
+    }
+
+    #[test]
+    fn test_fft_matches_naive_evaluation() {
+        let mut rng = test_rng();
+        let domain = EvaluationDomain::<Fr>::new(16).unwrap();
+        let poly = DensePolynomial::new(random_values(11, &mut rng));
+
+        let evaluations = poly.evaluate_over_domain(&domain);
+        for (i, x) in domain.elements().into_iter().enumerate() {
+            assert_eq!(evaluations[i], poly.evaluate(x));
+        }
+        assert_eq!(domain.ifft(&evaluations), {
+            let mut coefficients = poly.coefficients.clone();
+            coefficients.resize(16, Fr::from(0));
+            coefficients
+        });
+    }
+
+    #[test]
+    fn test_coset_fft() {
+        let mut rng = test_rng();
+        let domain = EvaluationDomain::<Fr>::new(8).unwrap();
+        let poly = DensePolynomial::new(random_values(8, &mut rng));
+
+        let evaluations = domain.coset_fft(&poly.coefficients);
+        for (i, x) in domain.elements().into_iter().enumerate() {
+            assert_eq!(evaluations[i], poly.evaluate(domain.coset_offset() * x));
+        }
+        assert_eq!(DensePolynomial::new(domain.coset_ifft(&evaluations)), poly);
+    }
+
+    #[test]
+    fn test_fft_of_high_degree_polynomial() {
+        let mut rng = test_rng();
+        let domain = EvaluationDomain::<Fr>::new(16).unwrap();
+        let poly = DensePolynomial::new(random_values(20, &mut rng));
+        assert_eq!(poly.degree(), 19);
+
+        let evaluations = poly.evaluate_over_domain(&domain);
+        let coset_evaluations = domain.coset_fft(&poly.coefficients);
+        for (i, x) in domain.elements().into_iter().enumerate() {
+            assert_eq!(evaluations[i], poly.evaluate(x));
+            assert_eq!(
+                coset_evaluations[i],
+                poly.evaluate(domain.coset_offset() * x)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Input length must not exceed the domain size")]
+    fn test_interpolate_too_many_evaluations() {
+        let domain = EvaluationDomain::<Fr>::new(4).unwrap();
+        DensePolynomial::interpolate_on_domain(&domain, &[Fr::from(1); 5]);
+    }
+
+    #[test]
+    fn test_interpolate_on_domain_matches_naive_interpolate() {
+        let mut rng = test_rng();
+        let domain = EvaluationDomain::<Fr>::new(8).unwrap();
+        let evaluations = random_values(8, &mut rng);
+
+        let points: Vec<(Fr, Fr)> = domain
+            .elements()
+            .into_iter()
+            .zip(evaluations.iter().copied())
+            .collect();
+
+        assert_eq!(
+            DensePolynomial::interpolate_on_domain(&domain, &evaluations),
+            DensePolynomial::interpolate(&points)
+        );
+    }
+
+    #[test]
+    fn test_ntt_mul_matches_schoolbook() {
+        let mut rng = test_rng();
+        let a = DensePolynomial::new(random_values(100, &mut rng));
+        let b = DensePolynomial::new(random_values(77, &mut rng));
+
+        let mut expected = vec![Fr::from(0); 176];
+        for (i, x) in a.coefficients.iter().enumerate() {
+            for (j, y) in b.coefficients.iter().enumerate() {
+                expected[i + j] += *x * y;
+            }
+        }
+
+        assert_eq!(ntt_mul(&a.coefficients, &b.coefficients).unwrap(), expected);
+        assert_eq!(&a * &b, DensePolynomial::new(expected));
+    }
+}
@@ -0,0 +1,201 @@
+use super::DensePolynomial;
+use ark_ff::{batch_inversion, PrimeField};
+
+/// A polynomial of degree `< n` given by its values at `n` distinct points.
+///
+/// The barycentric weights `w_i = 1 / prod_{j != i} (x_i - x_j)` are computed
+/// once, after which [`UnivariateEvals::evaluate`] runs in O(n) with a single
+/// field inversion and never builds the coefficients.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnivariateEvals<F: PrimeField> {
+    points: Vec<F>,
+    values: Vec<F>,
+    weights: Vec<F>,
+}
+
+impl<F: PrimeField> UnivariateEvals<F> {
+    /// Values at arbitrary distinct points. Weight precomputation is O(n^2).
+    pub fn new(points: Vec<F>, values: Vec<F>) -> Self {
+        assert_eq!(
+            points.len(),
+            values.len(),
+            "Number of points must match number of values"
+        );
+
+        let mut weights: Vec<F> = points
+            .iter()
+            .enumerate()
+            .map(|(i, &xi)| {
+                points
+                    .iter()
+                    .enumerate()
+                    .filter(|&(j, _)| j != i)
+                    .map(|(_, &xj)| xi - xj)
+                    .product()
+            })
+            .collect();
+        assert!(
+            weights.iter().all(|w| !w.is_zero()),
+            "interpolation points must have distinct x-coordinates"
+        );
+        batch_inversion(&mut weights);
+
+        UnivariateEvals {
+            points,
+            values,
+            weights,
+        }
+    }
+
+    pub fn from_points(points: &[(F, F)]) -> Self {
+        let (xs, ys) = points.iter().copied().unzip();
+        Self::new(xs, ys)
+    }
+
+    /// Values at `0, 1, ..., d`, the layout used by sumcheck round messages.
+    /// The weights `(-1)^(d-i) / (i! (d-i)!)` are computed in O(n).
+    pub fn from_consecutive(values: Vec<F>) -> Self {
+        let n = values.len();
+        let points: Vec<F> = (0..n as u64).map(F::from).collect();
+
+        // factorials[i] = i!
+        let mut factorials = vec![F::one(); n.max(1)];
+        for i in 1..n {
+            factorials[i] = factorials[i - 1] * F::from(i as u64);
+        }
+
+        let mut weights: Vec<F> = (0..n)
+            .map(|i| {
+                let w = factorials[i] * factorials[n - 1 - i];
+                if (n - 1 - i) % 2 == 1 {
+                    -w
+                } else {
+                    w
+                }
+            })
+            .collect();
+        batch_inversion(&mut weights);
+
+        UnivariateEvals {
+            points,
+            values,
+            weights,
+        }
+    }
+
+    pub fn points(&self) -> &[F] {
+        &self.points
+    }
+
+    pub fn values(&self) -> &[F] {
+        &self.values
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Evaluates with the second barycentric formula
+    /// `p(x) = (sum w_i y_i / (x - x_i)) / (sum w_i / (x - x_i))`.
+    pub fn evaluate(&self, x: F) -> F {
+        if self.is_empty() {
+            return F::zero();
+        }
+
+        if let Some(i) = self.points.iter().position(|&xi| xi == x) {
+            return self.values[i];
+        }
+
+        let mut terms: Vec<F> = self.points.iter().map(|&xi| x - xi).collect();
+        batch_inversion(&mut terms);
+
+        let mut numerator = F::zero();
+        let mut denominator = F::zero();
+        for ((term, &w), &y) in terms
+            .iter()
+            .zip(self.weights.iter())
+            .zip(self.values.iter())
+        {
+            let scaled = *term * w;
+            numerator += scaled * y;
+            denominator += scaled;
+        }
+        numerator * denominator.inverse().unwrap()
+    }
+
+    /// Materialises the coefficients, for callers that really need them.
+    pub fn to_dense(&self) -> DensePolynomial<F> {
+        let points: Vec<(F, F)> = self
+            .points
+            .iter()
+            .copied()
+            .zip(self.values.iter().copied())
+            .collect();
+        DensePolynomial::interpolate(&points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::random_poly;
+    use ark_bn254::Fr;
+    use ark_std::{test_rng, UniformRand};
+
+    #[test]
+    fn test_consecutive_matches_dense_evaluation() {
+        let mut rng = test_rng();
+        for degree in 0..6 {
+            let poly = random_poly(degree, &mut rng);
+            let values = (0..=degree as u64)
+                .map(|i| poly.evaluate(Fr::from(i)))
+                .collect();
+            let evals = UnivariateEvals::from_consecutive(values);
+
+            for _ in 0..4 {
+                let x = Fr::rand(&mut rng);
+                assert_eq!(evals.evaluate(x), poly.evaluate(x));
+            }
+            assert_eq!(evals.to_dense(), poly);
+        }
+    }
+
+    #[test]
+    fn test_consecutive_weights_match_general_weights() {
+        let values: Vec<Fr> = (0..5).map(|i| Fr::from(i * i + 3)).collect();
+        let points = (0..5).map(Fr::from).collect();
+        assert_eq!(
+            UnivariateEvals::from_consecutive(values.clone()),
+            UnivariateEvals::new(points, values)
+        );
+    }
+
+    #[test]
+    fn test_arbitrary_points() {
+        let mut rng = test_rng();
+        let points: Vec<(Fr, Fr)> = (0..7)
+            .map(|_| (Fr::rand(&mut rng), Fr::rand(&mut rng)))
+            .collect();
+        let evals = UnivariateEvals::from_points(&points);
+        let poly = DensePolynomial::interpolate(&points);
+
+        for &(x, y) in &points {
+            assert_eq!(evals.evaluate(x), y);
+        }
+        let x = Fr::rand(&mut rng);
+        assert_eq!(evals.evaluate(x), poly.evaluate(x));
+    }
+
+    #[test]
+    #[should_panic(expected = "interpolation points must have distinct x-coordinates")]
+    fn test_repeated_points() {
+        UnivariateEvals::new(
+            vec![Fr::from(1), Fr::from(1)],
+            vec![Fr::from(2), Fr::from(3)],
+        );
+    }
+}
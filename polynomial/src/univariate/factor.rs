@@ -0,0 +1,371 @@
+use super::DensePolynomial;
+use ark_ff::{BigInteger, BitIteratorBE, PrimeField};
+use ark_std::rand::{rngs::StdRng, Rng, SeedableRng};
+
+// Cantor–Zassenhaus is randomised; a fixed seed keeps `roots` and `factor`
+// deterministic, which matters when they are used to debug failing proofs.
+const SPLITTING_SEED: u64 = 0x5eed;
+
+impl<F: PrimeField> DensePolynomial<F> {
+    /// Distinct roots of the polynomial in `F`, in ascending order, or `None`
+    /// for the zero polynomial, which vanishes everywhere.
+    ///
+    /// Computes `gcd(X^p - X, self)`, the product of all linear factors, and
+    /// splits it with Cantor–Zassenhaus.
+    pub fn roots(&self) -> Option<Vec<F>> {
+        if self.is_zero() {
+            return None;
+        }
+        if self.degree() == 0 {
+            return Some(Vec::new());
+        }
+
+        let f = self.make_monic();
+        let x = DensePolynomial::new(vec![F::zero(), F::one()]);
+        let linear_part = (&pow_mod(&x, F::MODULUS, &f) - &x).gcd(&f);
+
+        let mut rng = StdRng::seed_from_u64(SPLITTING_SEED);
+        let mut roots: Vec<F> = equal_degree_factorization(&linear_part, 1, &mut rng)
+            .into_iter()
+            .map(|factor| -factor.coefficients[0])
+            .collect();
+        roots.sort();
+        Some(roots)
+    }
+
+    /// Monic irreducible factors with their multiplicities, ordered by degree
+    /// and then coefficients. The leading coefficient is not included, so the
+    /// product of the factors is `self.make_monic()`. `None` for the zero
+    /// polynomial.
+    ///
+    /// Runs square-free, distinct-degree and then equal-degree factorisation.
+    pub fn factor(&self) -> Option<Vec<(DensePolynomial<F>, usize)>> {
+        if self.is_zero() {
+            return None;
+        }
+
+        let mut rng = StdRng::seed_from_u64(SPLITTING_SEED);
+        let mut factors = Vec::new();
+        for (square_free, multiplicity) in square_free_factorization(&self.make_monic()) {
+            for (part, degree) in distinct_degree_factorization(&square_free) {
+                for factor in equal_degree_factorization(&part, degree, &mut rng) {
+                    factors.push((factor, multiplicity));
+                }
+            }
+        }
+
+        factors.sort_by(|(a, _), (b, _)| {
+            (a.degree(), &a.coefficients).cmp(&(b.degree(), &b.coefficients))
+        });
+        Some(factors)
+    }
+}
+
+/// `base^exponent mod modulus` by square-and-multiply over the exponent's limbs.
+fn pow_mod<F: PrimeField>(
+    base: &DensePolynomial<F>,
+    exponent: impl AsRef<[u64]>,
+    modulus: &DensePolynomial<F>,
+) -> DensePolynomial<F> {
+    let base = (base % modulus).unwrap();
+    let mut result = (&DensePolynomial::new(vec![F::one()]) % modulus).unwrap();
+    for bit in BitIteratorBE::without_leading_zeros(exponent) {
+        result = (&(&result * &result) % modulus).unwrap();
+        if bit {
+            result = (&(&result * &base) % modulus).unwrap();
+        }
+    }
+    result
+}
+
+/// The characteristic as a `usize`, when it fits.
+fn characteristic<F: PrimeField>() -> Option<usize> {
+    let modulus = F::MODULUS;
+    if modulus.num_bits() > usize::BITS {
+        return None;
+    }
+    Some(modulus.as_ref()[0] as usize)
+}
+
+/// For `f(X) = g(X^p)`, returns `g` (in a prime field `c^(1/p) = c`).
+fn pth_root<F: PrimeField>(f: &DensePolynomial<F>) -> DensePolynomial<F> {
+    let p = characteristic::<F>().expect("a zero derivative needs degree >= p");
+    DensePolynomial::new(f.coefficients.iter().step_by(p).copied().collect())
+}
+
+/// Splits a monic `f` into pairwise coprime square-free parts `(g_i, i)` with
+/// `f = prod g_i^i`.
+fn square_free_factorization<F: PrimeField>(
+    f: &DensePolynomial<F>,
+) -> Vec<(DensePolynomial<F>, usize)> {
+    if f.degree() == 0 {
+        return Vec::new();
+    }
+
+    let derivative = f.derivative();
+    if derivative.is_zero() {
+        let p = characteristic::<F>().unwrap();
+        return square_free_factorization(&pth_root(f))
+            .into_iter()
+            .map(|(g, multiplicity)| (g, multiplicity * p))
+            .collect();
+    }
+
+    let mut result = Vec::new();
+    let mut c = f.gcd(&derivative);
+    let mut w = (f / &c).unwrap();
+    let mut i = 1;
+
+    // w collects the factors whose multiplicity is not yet accounted for and
+    // is not divisible by p
+    while w.degree() > 0 {
+        let y = w.gcd(&c);
+        let factor = (&w / &y).unwrap();
+        if factor.degree() > 0 {
+            result.push((factor, i));
+        }
+        i += 1;
+        c = (&c / &y).unwrap();
+        w = y;
+    }
+
+    // Whatever is left is a p-th power
+    if c.degree() > 0 {
+        let p = characteristic::<F>().unwrap();
+        for (g, multiplicity) in square_free_factorization(&pth_root(&c)) {
+            result.push((g, multiplicity * p));
+        }
+    }
+    result
+}
+
+/// Splits a monic square-free `f` into `(g_d, d)`, where `g_d` is the product
+/// of all irreducible factors of degree `d`.
+fn distinct_degree_factorization<F: PrimeField>(
+    f: &DensePolynomial<F>,
+) -> Vec<(DensePolynomial<F>, usize)> {
+    let x = DensePolynomial::new(vec![F::zero(), F::one()]);
+    let mut result = Vec::new();
+    let mut rest = f.clone();
+    let mut h = (&x % &rest).unwrap();
+    let mut d = 1;
+
+    // h = X^(p^d) mod rest; gcd(X^(p^d) - X, rest) collects the degree-d factors
+    while rest.degree() >= 2 * d {
+        h = pow_mod(&h, F::MODULUS, &rest);
+        let g = (&h - &x).gcd(&rest);
+        if g.degree() > 0 {
+            rest = (&rest / &g).unwrap();
+            h = (&h % &rest).unwrap();
+            result.push((g, d));
+        }
+        d += 1;
+    }
+
+    if rest.degree() > 0 {
+        let degree = rest.degree();
+        result.push((rest, degree));
+    }
+    result
+}
+
+/// Cantor–Zassenhaus: splits a monic `f` whose irreducible factors all have
+/// degree `d` into those factors.
+fn equal_degree_factorization<F: PrimeField>(
+    f: &DensePolynomial<F>,
+    d: usize,
+    rng: &mut impl Rng,
+) -> Vec<DensePolynomial<F>> {
+    if f.degree() == 0 {
+        return Vec::new();
+    }
+    assert!(
+        F::MODULUS.is_odd(),
+        "Cantor–Zassenhaus needs odd characteristic"
+    );
+
+    let n = f.degree();
+    let target = n / d;
+    let one = DensePolynomial::new(vec![F::one()]);
+    let mut factors = vec![f.clone()];
+
+    while factors.len() < target {
+        // For random a, a^((p^d - 1) / 2) is +-1 modulo each factor
+        // independently, so gcd(a^((p^d - 1) / 2) - 1, u) splits u about half the time
+        let a = DensePolynomial::new((0..n).map(|_| F::rand(rng)).collect());
+        let b = &split_power(&a, d, f) - &one;
+
+        let mut next = Vec::with_capacity(factors.len() + 1);
+        for u in factors {
+            if u.degree() == d {
+                next.push(u);
+                continue;
+            }
+            let g = b.gcd(&u);
+            if g.degree() > 0 && g.degree() < u.degree() {
+                next.push((&u / &g).unwrap());
+                next.push(g);
+            } else {
+                next.push(u);
+            }
+        }
+        factors = next;
+    }
+    factors
+}
+
+/// `a^((p^d - 1) / 2) mod f`, computed as `N^((p - 1) / 2)` with the norm
+/// `N = a^(1 + p + ... + p^(d-1))` so the exponent never exceeds `p`.
+fn split_power<F: PrimeField>(
+    a: &DensePolynomial<F>,
+    d: usize,
+    f: &DensePolynomial<F>,
+) -> DensePolynomial<F> {
+    let mut power = (a % f).unwrap();
+    let mut norm = power.clone();
+    for _ in 1..d {
+        power = pow_mod(&power, F::MODULUS, f);
+        norm = (&(&norm * &power) % f).unwrap();
+    }
+    pow_mod(&norm, F::MODULUS_MINUS_ONE_DIV_TWO, f)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{linear, random_poly, random_values};
+    use ark_bn254::Fr;
+    use ark_ff::{Fp64, MontBackend, MontConfig};
+    use ark_std::test_rng;
+
+    #[derive(MontConfig)]
+    #[modulus = "17"]
+    #[generator = "3"]
+    struct F17Config;
+    type F17 = Fp64<MontBackend<F17Config, 1>>;
+
+    fn product<F: PrimeField>(factors: &[(DensePolynomial<F>, usize)]) -> DensePolynomial<F> {
+        factors
+            .iter()
+            .fold(DensePolynomial::new(vec![F::one()]), |acc, (f, m)| {
+                (0..*m).fold(acc, |acc, _| &acc * f)
+            })
+    }
+
+    #[test]
+    fn test_roots() {
+        // 5 generates Fr^*, so it is a non-residue and X^2 - 5 has no roots
+        let irreducible = DensePolynomial::new(vec![-Fr::from(5), Fr::from(0), Fr::from(1)]);
+        let p =
+            &(&(&linear(Fr::from(2)) * &linear(Fr::from(2))) * &linear(Fr::from(1))) * &irreducible;
+
+        assert_eq!(
+            (&p * Fr::from(9)).roots(),
+            Some(vec![Fr::from(1), Fr::from(2)])
+        );
+        assert_eq!(irreducible.roots(), Some(vec![]));
+        assert_eq!(
+            DensePolynomial::new(vec![Fr::from(3)]).roots(),
+            Some(vec![])
+        );
+        assert_eq!(linear(Fr::from(0)).roots(), Some(vec![Fr::from(0)]));
+    }
+
+    #[test]
+    fn test_zero_polynomial() {
+        assert_eq!(DensePolynomial::<Fr>::zero().roots(), None);
+        assert_eq!(DensePolynomial::<Fr>::zero().factor(), None);
+        assert_eq!(
+            DensePolynomial::new(vec![Fr::from(3)]).factor(),
+            Some(vec![])
+        );
+    }
+
+    #[test]
+    fn test_factor_known_polynomial() {
+        let irreducible = DensePolynomial::new(vec![-Fr::from(5), Fr::from(0), Fr::from(1)]);
+        let expected = vec![
+            (linear(Fr::from(1)), 2),
+            (linear(Fr::from(3)), 1),
+            (irreducible, 3),
+        ];
+        let p = &product(&expected) * Fr::from(7);
+
+        let mut factors = p.factor().unwrap();
+        factors.sort_by_key(|(f, _)| f.degree());
+        assert_eq!(factors.len(), 3);
+        assert_eq!(product(&factors), p.make_monic());
+        assert!(factors.contains(&expected[0]));
+        assert!(factors.contains(&expected[1]));
+        assert!(factors.contains(&expected[2]));
+    }
+
+    #[test]
+    fn test_factor_random_product() {
+        let mut rng = test_rng();
+        let parts: Vec<(DensePolynomial<Fr>, usize)> = [(1, 2), (2, 1), (3, 2), (1, 1), (4, 1)]
+            .iter()
+            .map(|&(degree, multiplicity)| {
+                let mut coefficients = random_values(degree, &mut rng);
+                coefficients.push(Fr::from(1));
+                (DensePolynomial::new(coefficients), multiplicity)
+            })
+            .collect();
+        let p = product(&parts);
+
+        let factors = p.factor().unwrap();
+        assert_eq!(product(&factors), p);
+        for (factor, _) in &factors {
+            assert!(factor.is_monic());
+            assert_eq!(factor.factor(), Some(vec![(factor.clone(), 1)]));
+        }
+    }
+
+    #[test]
+    fn test_small_field_frobenius() {
+        // X^17 - X vanishes on all of F_17
+        let mut coefficients = vec![F17::from(0); 18];
+        coefficients[1] = -F17::from(1);
+        coefficients[17] = F17::from(1);
+        let p = DensePolynomial::new(coefficients);
+
+        let all: Vec<F17> = (0..17u64).map(F17::from).collect();
+        assert_eq!(p.roots(), Some(all));
+        assert_eq!(p.factor().unwrap().len(), 17);
+    }
+
+    #[test]
+    fn test_small_field_pth_power() {
+        // (X + 1)^17 = X^17 + 1 has zero derivative in characteristic 17
+        let mut coefficients = vec![F17::from(0); 18];
+        coefficients[0] = F17::from(1);
+        coefficients[17] = F17::from(1);
+        let pth_power = DensePolynomial::new(coefficients);
+        let p = &(&pth_power * &linear(F17::from(5))) * &linear(-F17::from(1));
+
+        assert_eq!(
+            p.factor(),
+            Some(vec![(linear(-F17::from(1)), 18), (linear(F17::from(5)), 1)])
+        );
+    }
+
+    #[test]
+    fn test_counting_bad_sumcheck_challenges() {
+        // A cheating prover that sends g instead of the honest h survives the
+        // round check exactly when the challenge is a root of g - h, so at most
+        // deg(g - h) challenges out of |F| are bad.
+        let mut rng = test_rng();
+        let honest = random_poly(2, &mut rng);
+        let bad = [Fr::from(11), Fr::from(42)];
+        let cheating = &honest + &(&(&linear(bad[0]) * &linear(bad[1])) * Fr::from(3));
+
+        let difference = &cheating - &honest;
+        let roots = difference.roots().unwrap();
+        assert_eq!(roots, bad.to_vec());
+        assert!(roots.len() <= difference.degree());
+
+        // Identical round polynomials differ by zero, which vanishes
+        // everywhere, so roots() returns None rather than a list
+        assert_eq!((&honest - &honest).roots(), None);
+    }
+}
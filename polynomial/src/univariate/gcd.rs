@@ -0,0 +1,330 @@
+use super::DensePolynomial;
+use ark_ff::PrimeField;
+
+/// Once both inputs have at least this degree, `xgcd` switches from the
+/// classical Euclidean algorithm to half-GCD.
+const HALF_GCD_THRESHOLD: usize = 64;
+
+/// A 2x2 matrix of polynomials acting on remainder pairs `(a, b)`.
+type Matrix<F> = [[DensePolynomial<F>; 2]; 2];
+
+impl<F: PrimeField> DensePolynomial<F> {
+    /// Monic greatest common divisor. `gcd(0, 0)` is the zero polynomial.
+    pub fn gcd(&self, other: &DensePolynomial<F>) -> DensePolynomial<F> {
+        self.xgcd(other).0
+    }
+
+    /// Extended Euclid: returns `(g, s, t)` with `g = gcd(self, other)` monic
+    /// and `s * self + t * other = g`.
+    ///
+    /// Large inputs use the half-GCD algorithm, which needs O(M(n) log n)
+    /// operations instead of the O(n^2) of the classical remainder sequence.
+    pub fn xgcd(
+        &self,
+        other: &DensePolynomial<F>,
+    ) -> (DensePolynomial<F>, DensePolynomial<F>, DensePolynomial<F>) {
+        if self.degree().min(other.degree()) >= HALF_GCD_THRESHOLD {
+            half_gcd_xgcd(self, other)
+        } else {
+            euclid_xgcd(self, other)
+        }
+    }
+
+    /// Rational function reconstruction: finds `(n, d)` with `d` monic,
+    /// `deg n < num_degree_bound`, `deg d <= deg modulus - num_degree_bound` and
+    /// `n = d * self (mod modulus)`, stopping the extended Euclidean sequence of
+    /// `(modulus, self)` at the first remainder below the bound.
+    ///
+    /// Returns `None` when no such fraction with `d` coprime to `modulus` exists.
+    pub fn rational_reconstruction(
+        &self,
+        modulus: &DensePolynomial<F>,
+        num_degree_bound: usize,
+    ) -> Option<(DensePolynomial<F>, DensePolynomial<F>)> {
+        let k = num_degree_bound as isize;
+        if modulus.is_zero() || k > deg(modulus) {
+            return None;
+        }
+
+        let (mut r0, mut r1) = (modulus.clone(), (self % modulus).unwrap());
+        let (mut t0, mut t1) = (DensePolynomial::zero(), constant(F::one()));
+        while deg(&r1) >= k {
+            let (q, r) = r0.div_rem(&r1).unwrap();
+            let t = &t0 - &(&q * &t1);
+            (r0, r1) = (r1, r);
+            (t0, t1) = (t1, t);
+        }
+
+        // The denominator must be invertible modulo `modulus`
+        if deg(&t1) > deg(modulus) - k || t1.gcd(modulus) != constant(F::one()) {
+            return None;
+        }
+
+        let lead_inv = t1.leading_coefficient()?.inverse().unwrap();
+        Some((&r1 * lead_inv, &t1 * lead_inv))
+    }
+}
+
+/// Degree with the zero polynomial at -1, so `deg(0) < m` holds for every `m >= 0`.
+fn deg<F: PrimeField>(p: &DensePolynomial<F>) -> isize {
+    p.coefficients.len() as isize - 1
+}
+
+fn constant<F: PrimeField>(c: F) -> DensePolynomial<F> {
+    DensePolynomial::new(vec![c])
+}
+
+fn identity<F: PrimeField>() -> Matrix<F> {
+    [
+        [constant(F::one()), DensePolynomial::zero()],
+        [DensePolynomial::zero(), constant(F::one())],
+    ]
+}
+
+/// The Euclidean step `(a, b) -> (b, a - q b)`.
+fn quotient_step<F: PrimeField>(q: DensePolynomial<F>) -> Matrix<F> {
+    [
+        [DensePolynomial::zero(), constant(F::one())],
+        [constant(F::one()), -q],
+    ]
+}
+
+fn mul_matrix<F: PrimeField>(x: &Matrix<F>, y: &Matrix<F>) -> Matrix<F> {
+    let entry = |i: usize, j: usize| &(&x[i][0] * &y[0][j]) + &(&x[i][1] * &y[1][j]);
+    [[entry(0, 0), entry(0, 1)], [entry(1, 0), entry(1, 1)]]
+}
+
+fn apply<F: PrimeField>(
+    m: &Matrix<F>,
+    a: &DensePolynomial<F>,
+    b: &DensePolynomial<F>,
+) -> (DensePolynomial<F>, DensePolynomial<F>) {
+    (
+        &(&m[0][0] * a) + &(&m[0][1] * b),
+        &(&m[1][0] * a) + &(&m[1][1] * b),
+    )
+}
+
+/// `p div X^k`, i.e. the coefficients from degree `k` upwards.
+fn shift_down<F: PrimeField>(p: &DensePolynomial<F>, k: usize) -> DensePolynomial<F> {
+    DensePolynomial::new(p.coefficients.iter().skip(k).copied().collect())
+}
+
+/// Scales `(g, s, t)` so that `g` is monic.
+fn normalize<F: PrimeField>(
+    g: DensePolynomial<F>,
+    s: DensePolynomial<F>,
+    t: DensePolynomial<F>,
+) -> (DensePolynomial<F>, DensePolynomial<F>, DensePolynomial<F>) {
+    match g.leading_coefficient() {
+        Some(lead) => {
+            let lead_inv = lead.inverse().unwrap();
+            (&g * lead_inv, &s * lead_inv, &t * lead_inv)
+        }
+        None => (g, s, t),
+    }
+}
+
+fn euclid_xgcd<F: PrimeField>(
+    a: &DensePolynomial<F>,
+    b: &DensePolynomial<F>,
+) -> (DensePolynomial<F>, DensePolynomial<F>, DensePolynomial<F>) {
+    let (mut r0, mut r1) = (a.clone(), b.clone());
+    let (mut s0, mut s1) = (constant(F::one()), DensePolynomial::zero());
+    let (mut t0, mut t1) = (DensePolynomial::zero(), constant(F::one()));
+
+    while !r1.is_zero() {
+        let (q, r) = r0.div_rem(&r1).unwrap();
+        let s = &s0 - &(&q * &s1);
+        let t = &t0 - &(&q * &t1);
+        (r0, r1) = (r1, r);
+        (s0, s1) = (s1, s);
+        (t0, t1) = (t1, t);
+    }
+
+    normalize(r0, s0, t0)
+}
+
+/// Thull–Yap half-GCD. For `deg a > deg b` it returns a matrix `M` such that
+/// `(a', b') = M (a, b)` are consecutive remainders of the Euclidean sequence
+/// with `deg a' >= ceil(deg a / 2) > deg b'`. Only the top halves of the
+/// inputs are looked at in each recursive call.
+fn hgcd<F: PrimeField>(a: &DensePolynomial<F>, b: &DensePolynomial<F>) -> Matrix<F> {
+    let m = (deg(a) + 1) / 2;
+    if deg(b) < m {
+        return identity();
+    }
+    let m = m as usize;
+
+    let r = hgcd(&shift_down(a, m), &shift_down(b, m));
+    let (a1, b1) = apply(&r, a, b);
+    if deg(&b1) < m as isize {
+        return r;
+    }
+
+    let (q, d) = a1.div_rem(&b1).unwrap();
+    let l = deg(&b1) as usize;
+    let k = 2 * m - l;
+    let s = hgcd(&shift_down(&b1, k), &shift_down(&d, k));
+
+    mul_matrix(&mul_matrix(&s, &quotient_step(q)), &r)
+}
+
+fn half_gcd_xgcd<F: PrimeField>(
+    a: &DensePolynomial<F>,
+    b: &DensePolynomial<F>,
+) -> (DensePolynomial<F>, DensePolynomial<F>, DensePolynomial<F>) {
+    // Invariant: (x, y) = M (a, b)
+    let mut m = identity();
+    let (mut x, mut y) = (a.clone(), b.clone());
+
+    while !y.is_zero() {
+        // One classical step guarantees progress and deg x > deg y for hgcd
+        let (q, r) = x.div_rem(&y).unwrap();
+        m = mul_matrix(&quotient_step(q), &m);
+        (x, y) = (y, r);
+
+        if y.is_zero() || x.degree() < HALF_GCD_THRESHOLD {
+            continue;
+        }
+
+        let h = hgcd(&x, &y);
+        (x, y) = apply(&h, &x, &y);
+        m = mul_matrix(&h, &m);
+    }
+
+    let [[s, t], _] = m;
+    normalize(x, s, t)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{poly, random_poly};
+    use ark_bn254::Fr;
+    use ark_std::test_rng;
+
+    fn check_bezout(
+        a: &DensePolynomial<Fr>,
+        b: &DensePolynomial<Fr>,
+        (g, s, t): &(
+            DensePolynomial<Fr>,
+            DensePolynomial<Fr>,
+            DensePolynomial<Fr>,
+        ),
+    ) {
+        assert_eq!(&(s * a) + &(t * b), *g);
+        if !g.is_zero() {
+            assert!(g.is_monic());
+            assert!(a.is_divisible_by(g).unwrap());
+            assert!(b.is_divisible_by(g).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_leading_coefficient_and_monic() {
+        let p = poly(&[4, 0, 2]);
+        assert_eq!(p.leading_coefficient(), Some(Fr::from(2)));
+        assert!(!p.is_monic());
+
+        let monic = p.make_monic();
+        assert!(monic.is_monic());
+        assert_eq!(monic, poly(&[2, 0, 1]));
+
+        let zero = DensePolynomial::<Fr>::zero();
+        assert_eq!(zero.leading_coefficient(), None);
+        assert!(!zero.is_monic());
+        assert!(zero.make_monic().is_zero());
+    }
+
+    #[test]
+    fn test_gcd_of_known_factors() {
+        // (x + 1)(x + 2) and (x + 1)(x + 3)
+        let a = &poly(&[1, 1]) * &poly(&[2, 1]);
+        let b = &poly(&[1, 1]) * &poly(&[3, 1]);
+        assert_eq!(a.gcd(&b), poly(&[1, 1]));
+
+        // scaling does not change the monic gcd
+        assert_eq!((&a * Fr::from(5)).gcd(&(&b * Fr::from(7))), poly(&[1, 1]));
+    }
+
+    #[test]
+    fn test_gcd_edge_cases() {
+        let a = poly(&[3, 0, 6]);
+        let zero = DensePolynomial::zero();
+
+        assert_eq!(a.gcd(&zero), a.make_monic());
+        assert_eq!(zero.gcd(&a), a.make_monic());
+        assert!(zero.gcd(&zero).is_zero());
+        assert_eq!(a.gcd(&poly(&[5])), poly(&[1]));
+
+        for (x, y) in [(&a, &zero), (&zero, &a), (&zero, &zero)] {
+            check_bezout(x, y, &x.xgcd(y));
+        }
+    }
+
+    #[test]
+    fn test_xgcd_bezout_identity() {
+        let mut rng = test_rng();
+        for (n, m, k) in [(5, 3, 0), (8, 8, 2), (2, 9, 1), (20, 11, 4)] {
+            let common = random_poly(k, &mut rng);
+            let a = &random_poly(n, &mut rng) * &common;
+            let b = &random_poly(m, &mut rng) * &common;
+
+            let result = a.xgcd(&b);
+            check_bezout(&a, &b, &result);
+            assert_eq!(result.0, common.make_monic());
+        }
+    }
+
+    #[test]
+    fn test_half_gcd_matches_euclid() {
+        let mut rng = test_rng();
+        for (n, m, k) in [(150, 120, 30), (200, 200, 0), (130, 90, 70), (100, 300, 5)] {
+            let common = random_poly(k, &mut rng);
+            let a = &random_poly(n, &mut rng) * &common;
+            let b = &random_poly(m, &mut rng) * &common;
+
+            let fast = half_gcd_xgcd(&a, &b);
+            check_bezout(&a, &b, &fast);
+            assert_eq!(fast.0, common.make_monic());
+            assert_eq!(fast.0, euclid_xgcd(&a, &b).0);
+        }
+    }
+
+    #[test]
+    fn test_hgcd_halves_the_degree() {
+        let mut rng = test_rng();
+        let a = random_poly(101, &mut rng);
+        let b = random_poly(100, &mut rng);
+
+        let (a1, b1) = apply(&hgcd(&a, &b), &a, &b);
+        assert!(deg(&a1) >= 51);
+        assert!(deg(&b1) < 51);
+    }
+
+    #[test]
+    fn test_rational_reconstruction() {
+        let mut rng = test_rng();
+        let xs: Vec<Fr> = (1..=10).map(Fr::from).collect();
+        let modulus = xs.iter().fold(poly(&[1]), |acc, &x| {
+            &acc * &DensePolynomial::new(vec![-x, Fr::from(1)])
+        });
+
+        let numerator = random_poly(3, &mut rng);
+        let denominator = random_poly(4, &mut rng).make_monic();
+
+        // p = numerator / denominator on every point, i.e. modulo `modulus`
+        let points: Vec<(Fr, Fr)> = xs
+            .iter()
+            .map(|&x| (x, numerator.evaluate(x) / denominator.evaluate(x)))
+            .collect();
+        let p = DensePolynomial::interpolate(&points);
+
+        assert_eq!(
+            p.rational_reconstruction(&modulus, 4),
+            Some((numerator, denominator))
+        );
+        assert_eq!(p.rational_reconstruction(&modulus, 11), None);
+    }
+}
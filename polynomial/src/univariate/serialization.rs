@@ -0,0 +1,222 @@
+use super::DensePolynomial;
+use ark_ff::PrimeField;
+use ark_serialize::{
+    CanonicalDeserialize, CanonicalSerialize, Compress, Read, SerializationError, Valid, Validate,
+    Write,
+};
+use field_codec::SERIALIZATION_VERSION;
+
+// Layout: version byte, then the coefficient vector (u64 length followed by
+// the field elements in the requested compression mode).
+
+impl<F: PrimeField> Valid for DensePolynomial<F> {
+    fn check(&self) -> Result<(), SerializationError> {
+        // `DensePolynomial::new` never leaves a trailing zero, so one on the
+        // wire means the encoding is not canonical
+        if self.coefficients.last().is_some_and(|c| c.is_zero()) {
+            return Err(SerializationError::InvalidData);
+        }
+        self.coefficients.check()
+    }
+}
+
+impl<F: PrimeField> CanonicalSerialize for DensePolynomial<F> {
+    fn serialize_with_mode<W: Write>(
+        &self,
+        mut writer: W,
+        compress: Compress,
+    ) -> Result<(), SerializationError> {
+        SERIALIZATION_VERSION.serialize_with_mode(&mut writer, compress)?;
+        self.coefficients.serialize_with_mode(&mut writer, compress)
+    }
+
+    fn serialized_size(&self, compress: Compress) -> usize {
+        SERIALIZATION_VERSION.serialized_size(compress)
+            + self.coefficients.serialized_size(compress)
+    }
+}
+
+impl<F: PrimeField> CanonicalDeserialize for DensePolynomial<F> {
+    fn deserialize_with_mode<R: Read>(
+        mut reader: R,
+        compress: Compress,
+        validate: Validate,
+    ) -> Result<Self, SerializationError> {
+        let version = u8::deserialize_with_mode(&mut reader, compress, validate)?;
+        if version != SERIALIZATION_VERSION {
+            return Err(SerializationError::InvalidData);
+        }
+
+        // Same wire format as `Vec<F>`, but grown as coefficients arrive
+        // instead of pre-allocating whatever length the input claims
+        let len = u64::deserialize_with_mode(&mut reader, compress, validate)?;
+        let len = usize::try_from(len).map_err(|_| SerializationError::InvalidData)?;
+        let mut coefficients = Vec::new();
+        for _ in 0..len {
+            coefficients.push(F::deserialize_with_mode(&mut reader, compress, validate)?);
+        }
+        let poly = DensePolynomial { coefficients };
+        if validate == Validate::Yes {
+            poly.check()?;
+        }
+        Ok(poly)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::{DensePolynomial, SERIALIZATION_VERSION};
+    use ark_ff::PrimeField;
+    use field_codec::hex::{field_to_hex, hex_to_field};
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Human-readable form: field elements as big-endian hex strings.
+    #[derive(Serialize, Deserialize)]
+    struct HexPolynomial {
+        version: u8,
+        coefficients: Vec<String>,
+    }
+
+    impl<F: PrimeField> Serialize for DensePolynomial<F> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            HexPolynomial {
+                version: SERIALIZATION_VERSION,
+                coefficients: self.coefficients.iter().map(field_to_hex).collect(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de, F: PrimeField> Deserialize<'de> for DensePolynomial<F> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let raw = HexPolynomial::deserialize(deserializer)?;
+            if raw.version != SERIALIZATION_VERSION {
+                return Err(D::Error::custom(format!(
+                    "unsupported polynomial version {}",
+                    raw.version
+                )));
+            }
+
+            let coefficients = raw
+                .coefficients
+                .iter()
+                .map(|s| hex_to_field(s))
+                .collect::<Result<Vec<F>, String>>()
+                .map_err(D::Error::custom)?;
+            if coefficients.last().is_some_and(|c| c.is_zero()) {
+                return Err(D::Error::custom(
+                    "polynomial has a trailing zero coefficient",
+                ));
+            }
+            Ok(DensePolynomial { coefficients })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::random_poly;
+    use ark_bn254::Fr;
+    use ark_std::test_rng;
+
+    #[test]
+    fn test_round_trip_in_both_modes() {
+        for poly in [random_poly(9, &mut test_rng()), DensePolynomial::zero()] {
+            for compress in [Compress::Yes, Compress::No] {
+                let mut bytes = Vec::new();
+                poly.serialize_with_mode(&mut bytes, compress).unwrap();
+                assert_eq!(bytes.len(), poly.serialized_size(compress));
+                assert_eq!(bytes[0], SERIALIZATION_VERSION);
+
+                let decoded = DensePolynomial::<Fr>::deserialize_with_mode(
+                    &bytes[..],
+                    compress,
+                    Validate::Yes,
+                )
+                .unwrap();
+                assert_eq!(decoded, poly);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rejects_unknown_version() {
+        let mut bytes = Vec::new();
+        random_poly(3, &mut test_rng())
+            .serialize_compressed(&mut bytes)
+            .unwrap();
+        bytes[0] = SERIALIZATION_VERSION + 1;
+
+        assert!(matches!(
+            DensePolynomial::<Fr>::deserialize_compressed(&bytes[..]),
+            Err(SerializationError::InvalidData)
+        ));
+    }
+
+    #[test]
+    fn test_rejects_trailing_zero() {
+        let non_canonical = DensePolynomial {
+            coefficients: vec![Fr::from(1), Fr::from(0)],
+        };
+        let mut bytes = Vec::new();
+        non_canonical.serialize_compressed(&mut bytes).unwrap();
+
+        assert!(matches!(
+            DensePolynomial::<Fr>::deserialize_compressed(&bytes[..]),
+            Err(SerializationError::InvalidData)
+        ));
+        assert!(DensePolynomial::<Fr>::deserialize_compressed_unchecked(&bytes[..]).is_ok());
+    }
+
+    #[test]
+    fn test_rejects_truncated_input() {
+        let mut bytes = Vec::new();
+        random_poly(3, &mut test_rng())
+            .serialize_compressed(&mut bytes)
+            .unwrap();
+        bytes.pop();
+
+        assert!(DensePolynomial::<Fr>::deserialize_compressed(&bytes[..]).is_err());
+    }
+
+    #[test]
+    fn test_rejects_huge_length_prefix() {
+        let mut bytes = vec![SERIALIZATION_VERSION];
+        bytes.extend_from_slice(&u64::MAX.to_le_bytes());
+
+        assert!(matches!(
+            DensePolynomial::<Fr>::deserialize_compressed(&bytes[..]),
+            Err(SerializationError::IoError(_)) | Err(SerializationError::InvalidData)
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_json_round_trip() {
+        let poly = DensePolynomial::new(vec![Fr::from(1), Fr::from(0), -Fr::from(1)]);
+        let json = serde_json::to_string(&poly).unwrap();
+        assert!(
+            json.contains("\"0000000000000000000000000000000000000000000000000000000000000001\"")
+        );
+
+        let decoded: DensePolynomial<Fr> = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, poly);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_rejects_bad_input() {
+        // the BN254 scalar field modulus itself is not a canonical element
+        let modulus = "30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001";
+        for json in [
+            format!(r#"{{"version":1,"coefficients":["{modulus}"]}}"#),
+            r#"{"version":1,"coefficients":["zz"]}"#.to_string(),
+            r#"{"version":1,"coefficients":["01","00"]}"#.to_string(),
+            r#"{"version":2,"coefficients":[]}"#.to_string(),
+        ] {
+            assert!(serde_json::from_str::<DensePolynomial<Fr>>(&json).is_err());
+        }
+    }
+}
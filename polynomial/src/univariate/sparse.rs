@@ -0,0 +1,292 @@
+use super::DensePolynomial;
+use ark_ff::PrimeField;
+use std::collections::BTreeMap;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A univariate polynomial stored as its non-zero `(degree, coefficient)`
+/// terms, sorted by degree. Suited to `X^n - 1` and selector polynomials,
+/// where the degree is huge but only a handful of terms are non-zero.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparsePolynomial<F: PrimeField> {
+    terms: Vec<(usize, F)>,
+}
+
+impl<F: PrimeField> SparsePolynomial<F> {
+    /// Terms may come in any order; repeated degrees are summed and zero
+    /// coefficients dropped.
+    pub fn new(terms: Vec<(usize, F)>) -> Self {
+        let mut merged: BTreeMap<usize, F> = BTreeMap::new();
+        for (degree, coefficient) in terms {
+            *merged.entry(degree).or_insert_with(F::zero) += coefficient;
+        }
+        SparsePolynomial {
+            terms: merged.into_iter().filter(|(_, c)| !c.is_zero()).collect(),
+        }
+    }
+
+    pub fn zero() -> Self {
+        SparsePolynomial { terms: Vec::new() }
+    }
+
+    /// `X^n - 1`, the vanishing polynomial of the order-`n` subgroup.
+    pub fn vanishing(n: usize) -> Self {
+        SparsePolynomial::new(vec![(0, -F::one()), (n, F::one())])
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    pub fn terms(&self) -> &[(usize, F)] {
+        &self.terms
+    }
+
+    pub fn degree(&self) -> usize {
+        self.terms.last().map_or(0, |&(degree, _)| degree)
+    }
+
+    /// Walks the terms in increasing degree, raising `x` only by the gap
+    /// between consecutive degrees: O(t log n) multiplications and no
+    /// allocation proportional to the degree.
+    pub fn evaluate(&self, x: F) -> F {
+        let mut result = F::zero();
+        let mut power = F::one();
+        let mut previous_degree = 0;
+        for &(degree, coefficient) in &self.terms {
+            power *= x.pow([(degree - previous_degree) as u64]);
+            previous_degree = degree;
+            result += coefficient * power;
+        }
+        result
+    }
+}
+
+impl<F: PrimeField> From<SparsePolynomial<F>> for DensePolynomial<F> {
+    fn from(sparse: SparsePolynomial<F>) -> Self {
+        if sparse.is_zero() {
+            return DensePolynomial::zero();
+        }
+        let mut coefficients = vec![F::zero(); sparse.degree() + 1];
+        for (degree, coefficient) in sparse.terms {
+            coefficients[degree] = coefficient;
+        }
+        DensePolynomial::new(coefficients)
+    }
+}
+
+impl<F: PrimeField> From<DensePolynomial<F>> for SparsePolynomial<F> {
+    fn from(dense: DensePolynomial<F>) -> Self {
+        SparsePolynomial {
+            terms: dense
+                .coefficients
+                .into_iter()
+                .enumerate()
+                .filter(|(_, c)| !c.is_zero())
+                .collect(),
+        }
+    }
+}
+
+impl<F: PrimeField> Add<&SparsePolynomial<F>> for &SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn add(self, rhs: &SparsePolynomial<F>) -> SparsePolynomial<F> {
+        SparsePolynomial::new(self.terms.iter().chain(rhs.terms.iter()).copied().collect())
+    }
+}
+
+impl<F: PrimeField> Neg for &SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn neg(self) -> SparsePolynomial<F> {
+        SparsePolynomial {
+            terms: self.terms.iter().map(|&(d, c)| (d, -c)).collect(),
+        }
+    }
+}
+
+impl<F: PrimeField> Sub<&SparsePolynomial<F>> for &SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn sub(self, rhs: &SparsePolynomial<F>) -> SparsePolynomial<F> {
+        self + &(-rhs)
+    }
+}
+
+impl<F: PrimeField> Mul<&SparsePolynomial<F>> for &SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn mul(self, rhs: &SparsePolynomial<F>) -> SparsePolynomial<F> {
+        let mut terms = Vec::with_capacity(self.terms.len() * rhs.terms.len());
+        for &(da, ca) in &self.terms {
+            for &(db, cb) in &rhs.terms {
+                terms.push((da + db, ca * cb));
+            }
+        }
+        SparsePolynomial::new(terms)
+    }
+}
+
+impl<F: PrimeField> Mul<F> for &SparsePolynomial<F> {
+    type Output = SparsePolynomial<F>;
+
+    fn mul(self, scalar: F) -> SparsePolynomial<F> {
+        SparsePolynomial::new(self.terms.iter().map(|&(d, c)| (d, c * scalar)).collect())
+    }
+}
+
+// Mixed dense/sparse arithmetic produces dense polynomials and only touches
+// the coefficients that the sparse terms hit.
+
+impl<F: PrimeField> Add<&SparsePolynomial<F>> for &DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn add(self, rhs: &SparsePolynomial<F>) -> DensePolynomial<F> {
+        let mut coefficients = self.coefficients.clone();
+        if coefficients.len() <= rhs.degree() {
+            coefficients.resize(rhs.degree() + 1, F::zero());
+        }
+        for &(degree, coefficient) in &rhs.terms {
+            coefficients[degree] += coefficient;
+        }
+        DensePolynomial::new(coefficients)
+    }
+}
+
+impl<F: PrimeField> Add<&DensePolynomial<F>> for &SparsePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn add(self, rhs: &DensePolynomial<F>) -> DensePolynomial<F> {
+        rhs + self
+    }
+}
+
+impl<F: PrimeField> Sub<&SparsePolynomial<F>> for &DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn sub(self, rhs: &SparsePolynomial<F>) -> DensePolynomial<F> {
+        self + &(-rhs)
+    }
+}
+
+impl<F: PrimeField> Sub<&DensePolynomial<F>> for &SparsePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn sub(self, rhs: &DensePolynomial<F>) -> DensePolynomial<F> {
+        &(-rhs) + self
+    }
+}
+
+impl<F: PrimeField> Mul<&SparsePolynomial<F>> for &DensePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn mul(self, rhs: &SparsePolynomial<F>) -> DensePolynomial<F> {
+        if self.is_zero() || rhs.is_zero() {
+            return DensePolynomial::zero();
+        }
+        let mut coefficients = vec![F::zero(); self.coefficients.len() + rhs.degree()];
+        for &(degree, coefficient) in &rhs.terms {
+            for (i, &c) in self.coefficients.iter().enumerate() {
+                coefficients[i + degree] += c * coefficient;
+            }
+        }
+        DensePolynomial::new(coefficients)
+    }
+}
+
+impl<F: PrimeField> Mul<&DensePolynomial<F>> for &SparsePolynomial<F> {
+    type Output = DensePolynomial<F>;
+
+    fn mul(self, rhs: &DensePolynomial<F>) -> DensePolynomial<F> {
+        rhs * self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{random_poly, random_sparse};
+    use ark_bn254::Fr;
+    use ark_ff::{FftField, Field};
+    use ark_std::{test_rng, UniformRand};
+
+    #[test]
+    fn test_new_normalizes_terms() {
+        let p = SparsePolynomial::new(vec![
+            (7, Fr::from(2)),
+            (0, Fr::from(1)),
+            (7, -Fr::from(2)),
+            (3, Fr::from(4)),
+            (5, Fr::from(0)),
+        ]);
+        assert_eq!(p.terms(), &[(0, Fr::from(1)), (3, Fr::from(4))]);
+        assert_eq!(p.degree(), 3);
+    }
+
+    #[test]
+    fn test_vanishing_evaluation_at_huge_degree() {
+        let n = 1usize << 40;
+        let vanishing = SparsePolynomial::<Fr>::vanishing(n);
+        assert_eq!(vanishing.degree(), n);
+
+        let x = Fr::from(3);
+        assert_eq!(vanishing.evaluate(x), x.pow([n as u64]) - Fr::from(1));
+
+        // every 8th root of unity is a root of X^8 - 1
+        let root = Fr::get_root_of_unity(8).unwrap();
+        assert_eq!(SparsePolynomial::vanishing(8).evaluate(root), Fr::from(0));
+    }
+
+    #[test]
+    fn test_dense_round_trip() {
+        let mut rng = test_rng();
+        for _ in 0..5 {
+            let sparse = random_sparse(&mut rng);
+            let dense = DensePolynomial::from(sparse.clone());
+            let x = Fr::rand(&mut rng);
+
+            assert_eq!(dense.evaluate(x), sparse.evaluate(x));
+            assert_eq!(SparsePolynomial::from(dense), sparse);
+        }
+        assert!(DensePolynomial::from(SparsePolynomial::<Fr>::zero()).is_zero());
+    }
+
+    #[test]
+    fn test_arithmetic_matches_dense() {
+        let mut rng = test_rng();
+        for _ in 0..5 {
+            let a = random_sparse(&mut rng);
+            let b = random_sparse(&mut rng);
+            let dense_a = DensePolynomial::from(a.clone());
+            let dense_b = DensePolynomial::from(b.clone());
+
+            assert_eq!(DensePolynomial::from(&a + &b), &dense_a + &dense_b);
+            assert_eq!(DensePolynomial::from(&a - &b), &dense_a - &dense_b);
+            assert_eq!(DensePolynomial::from(&a * &b), &dense_a * &dense_b);
+            assert_eq!(
+                DensePolynomial::from(&a * Fr::from(3)),
+                &dense_a * Fr::from(3)
+            );
+
+            assert_eq!(&dense_a + &b, &dense_a + &dense_b);
+            assert_eq!(&a + &dense_b, &dense_a + &dense_b);
+            assert_eq!(&dense_a - &b, &dense_a - &dense_b);
+            assert_eq!(&a - &dense_b, &dense_a - &dense_b);
+            assert_eq!(&dense_a * &b, &dense_a * &dense_b);
+            assert_eq!(&a * &dense_b, &dense_a * &dense_b);
+        }
+    }
+
+    #[test]
+    fn test_vanishing_times_quotient() {
+        let mut rng = test_rng();
+        let quotient = random_poly(5, &mut rng);
+        let vanishing = SparsePolynomial::vanishing(4);
+
+        let product = &quotient * &vanishing;
+        assert_eq!(
+            product.divide_by_vanishing_poly(4).unwrap(),
+            (quotient, DensePolynomial::zero())
+        );
+    }
+}
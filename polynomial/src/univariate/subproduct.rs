@@ -0,0 +1,160 @@
+use super::DensePolynomial;
+use ark_ff::PrimeField;
+
+/// Subtrees with at most this many points are handled directly: their
+/// remainders are evaluated point by point instead of being split further.
+const LEAF_SIZE: usize = 16;
+
+/// Binary tree whose node polynomials are `prod (X - x_i)` over the points
+/// below them; the root is the vanishing polynomial of the whole point set.
+struct SubproductTree<'a, F: PrimeField> {
+    points: &'a [F],
+    poly: DensePolynomial<F>,
+    children: Option<Box<(SubproductTree<'a, F>, SubproductTree<'a, F>)>>,
+}
+
+impl<'a, F: PrimeField> SubproductTree<'a, F> {
+    fn new(points: &'a [F]) -> Self {
+        if points.len() <= LEAF_SIZE {
+            let poly = points
+                .iter()
+                .fold(DensePolynomial::new(vec![F::one()]), |acc, &x| {
+                    &acc * &DensePolynomial::new(vec![-x, F::one()])
+                });
+            return SubproductTree {
+                points,
+                poly,
+                children: None,
+            };
+        }
+
+        let (left, right) = points.split_at(points.len() / 2);
+        let left = SubproductTree::new(left);
+        let right = SubproductTree::new(right);
+        SubproductTree {
+            points,
+            poly: &left.poly * &right.poly,
+            children: Some(Box::new((left, right))),
+        }
+    }
+
+    /// Pushes `p(x_i)` for every point below this node, in order. Reducing
+    /// modulo the node polynomial first keeps the degrees halving on the way down.
+    fn evaluate(&self, p: &DensePolynomial<F>, out: &mut Vec<F>) {
+        let remainder = (p % &self.poly).unwrap();
+        match &self.children {
+            None => out.extend(self.points.iter().map(|&x| remainder.evaluate(x))),
+            Some(children) => {
+                children.0.evaluate(&remainder, out);
+                children.1.evaluate(&remainder, out);
+            }
+        }
+    }
+
+    /// Returns `sum_i c_i * prod_{j != i} (X - x_j)` over the points below this node.
+    fn linear_combination(&self, c: &[F]) -> DensePolynomial<F> {
+        match &self.children {
+            None => {
+                self.points
+                    .iter()
+                    .zip(c.iter())
+                    .fold(DensePolynomial::zero(), |acc, (&x, &ci)| {
+                        let (basis, _) = self.poly.divide_by_linear(x);
+                        &acc + &(&basis * ci)
+                    })
+            }
+            Some(children) => {
+                let (left, right) = &**children;
+                let (c_left, c_right) = c.split_at(left.points.len());
+                &(&left.linear_combination(c_left) * &right.poly)
+                    + &(&right.linear_combination(c_right) * &left.poly)
+            }
+        }
+    }
+}
+
+impl<F: PrimeField> DensePolynomial<F> {
+    /// Evaluates the polynomial at arbitrary points in O(n log^2 n) using a
+    /// subproduct tree.
+    pub fn multi_evaluate(&self, points: &[F]) -> Vec<F> {
+        if points.is_empty() {
+            return Vec::new();
+        }
+
+        let mut result = Vec::with_capacity(points.len());
+        SubproductTree::new(points).evaluate(self, &mut result);
+        result
+    }
+
+    /// Lagrange interpolation in O(n log^2 n) for arbitrary distinct points.
+    ///
+    /// With `M = prod (X - x_i)`, the result is
+    /// `sum_i y_i / M'(x_i) * M(X) / (X - x_i)`; the weights `M'(x_i)` come from
+    /// one multipoint evaluation and the sum is assembled up the tree.
+    pub fn interpolate_fast(points: &[(F, F)]) -> Self {
+        if points.is_empty() {
+            return DensePolynomial::zero();
+        }
+
+        let (xs, ys): (Vec<F>, Vec<F>) = points.iter().copied().unzip();
+        let tree = SubproductTree::new(&xs);
+
+        let mut weights = Vec::with_capacity(xs.len());
+        tree.evaluate(&tree.poly.derivative(), &mut weights);
+        assert!(
+            weights.iter().all(|w| !w.is_zero()),
+            "interpolation points must have distinct x-coordinates"
+        );
+        ark_ff::batch_inversion(&mut weights);
+
+        let scaled: Vec<F> = ys.iter().zip(weights.iter()).map(|(y, w)| *y * w).collect();
+        tree.linear_combination(&scaled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::random_values;
+    use ark_bn254::Fr;
+    use ark_std::test_rng;
+
+    #[test]
+    fn test_multi_evaluate_matches_evaluate() {
+        let mut rng = test_rng();
+        for (degree, n) in [(0, 1), (5, 3), (40, 100), (150, 37), (200, 200)] {
+            let poly = DensePolynomial::new(random_values(degree + 1, &mut rng));
+            let points = random_values(n, &mut rng);
+
+            let expected: Vec<Fr> = points.iter().map(|&x| poly.evaluate(x)).collect();
+            assert_eq!(poly.multi_evaluate(&points), expected);
+        }
+        assert!(DensePolynomial::<Fr>::zero().multi_evaluate(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_interpolate_fast_matches_interpolate() {
+        let mut rng = test_rng();
+        for n in [1, 2, 16, 17, 50] {
+            let points: Vec<(Fr, Fr)> = random_values(n, &mut rng)
+                .into_iter()
+                .zip(random_values(n, &mut rng))
+                .collect();
+
+            assert_eq!(
+                DensePolynomial::interpolate_fast(&points),
+                DensePolynomial::interpolate(&points)
+            );
+        }
+    }
+
+    #[test]
+    fn test_interpolate_fast_round_trip() {
+        let mut rng = test_rng();
+        let poly = DensePolynomial::new(random_values(300, &mut rng));
+        let xs: Vec<Fr> = (1..=300).map(Fr::from).collect();
+        let points: Vec<(Fr, Fr)> = xs.iter().copied().zip(poly.multi_evaluate(&xs)).collect();
+
+        assert_eq!(DensePolynomial::interpolate_fast(&points), poly);
+    }
+}
@@ -1,58 +1,50 @@
+use ark_bn254::Fr;
 use ark_ff::PrimeField;
+use ark_std::rand::{rngs::StdRng, SeedableRng};
+use polynomial::univariate::{DensePolynomial, UnivariateEvals};
 use rand::Rng;
-// extern crate univariate;
-use shamir::univariate::DensePolynomial;
 
-fn split_secret(secret: f64, n: u32, quorum: u32) -> Vec<(f64, f64)> {
-    let mut points: Vec<(f64, f64)> = vec![(0.0, secret)];
+fn split_secret<F: PrimeField>(secret: F, n: u32, quorum: u32) -> Vec<(F, F)> {
+    assert!(quorum >= 1 && quorum <= n, "quorum must be between 1 and n");
 
-    let mut rng = rand::thread_rng();
+    // UniformRand samples the field without the bias of reducing random
+    // bytes mod p; its RNG is seeded from the OS
+    let mut rng = StdRng::from_seed(rand::rng().random());
 
+    // f(0) = secret, the remaining quorum - 1 coefficients are random
+    let mut coefficients = vec![secret];
     for _i in 0..quorum - 1 {
-        let x: f64 = rng.gen_range(1.0..10000000.0);
-        let y: f64 = rng.gen_range(1.0..10000000.0);
-
-        if y == secret {
-            panic!("failed!");
-        }
-
-        points.push((x, y));
-
-        dbg!(&points);
+        coefficients.push(F::rand(&mut rng));
     }
 
-    let poly = DensePolynomial::interpolate(&points);
+    let poly = DensePolynomial::new(coefficients);
 
-    let mut shares: Vec<(f64, f64)> = Vec::new();
+    let xs: Vec<F> = (1..=n).map(F::from).collect();
+    let ys = poly.evaluate_many(&xs);
 
-    for i in 1..=n {
-        // let x: f64 = rng.gen_range(1.0..100.0);
-        let x: f64 = i.into();
-
-        dbg!(poly.evaluate(x));
-
-        let y = poly.evaluate(x);
-        shares.push((x, y));
-    }
+    xs.into_iter().zip(ys).collect()
+}
 
-    shares
+fn get_secret<F: PrimeField>(secret_shares: Vec<(F, F)>) -> F {
+    // Only f(0) is needed, so skip building the coefficients
+    UnivariateEvals::from_points(&secret_shares).evaluate(F::zero())
 }
 
-fn get_secret(secret_shares: Vec<(f64, f64)>) -> f64 {
-    // let xValues: Vec<f64> = secret_shares.iter().map(|(x, _)| *x).collect();
-    // let yValues: Vec<f64> = secret_shares.iter().map(|(_, y)| *y).collect();
-    let result_poly = DensePolynomial::interpolate(&secret_shares);
-    result_poly.evaluate(0.0)
+fn main() {
+    let secret = Fr::from(42);
+    let shares = split_secret(secret, 5, 3);
+    println!("Shares: {:?}", shares);
+
+    let recovered = get_secret(shares.into_iter().take(3).collect());
+    println!("Recovered secret: {}", recovered);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ark_bn254::Fq;
-    use std::i32;
 
-    fn return_values() -> (f64, u32, u32) {
-        let secret = f64::MAX;
+    fn return_values() -> (Fr, u32, u32) {
+        let secret = -Fr::from(1);
         let quorum = 4;
         let share_number = 10;
         (secret, quorum, share_number)
@@ -60,12 +52,9 @@ mod tests {
 
     #[test]
     fn test_share_secret() {
-        let secret = f64::MAX;
-        let quorum = 4;
-        let shares_no = 10;
+        let (secret, quorum, shares_no) = return_values();
         let shares = split_secret(secret, shares_no, quorum);
 
-        dbg!(&shares);
         assert_eq!(shares.len(), shares_no as usize);
     }
 
@@ -73,14 +62,27 @@ mod tests {
     fn test_get_secret_success() {
         let (secret, quorum, share_number) = return_values();
         let shares = split_secret(secret, share_number, quorum);
-        let first_shares: Vec<(f64, f64)> = shares.iter().take(4).cloned().collect();
+        let first_shares: Vec<(Fr, Fr)> = shares.iter().take(4).cloned().collect();
 
-        dbg!(&first_shares);
         let generated_secret = get_secret(first_shares);
         assert_eq!(secret, generated_secret);
     }
-}
 
-fn main() {
-    println!("Hello, world!");
+    #[test]
+    fn test_get_secret_from_any_quorum() {
+        let (secret, quorum, share_number) = return_values();
+        let shares = split_secret(secret, share_number, quorum);
+        let last_shares: Vec<(Fr, Fr)> = shares.iter().rev().take(4).cloned().collect();
+
+        assert_eq!(get_secret(last_shares), secret);
+    }
+
+    #[test]
+    fn test_get_secret_below_quorum_fails() {
+        let (secret, quorum, share_number) = return_values();
+        let shares = split_secret(secret, share_number, quorum);
+        let too_few: Vec<(Fr, Fr)> = shares.iter().take(3).cloned().collect();
+
+        assert_ne!(get_secret(too_few), secret);
+    }
 }
@@ -27,7 +27,7 @@ pub struct Verifier<F: PrimeField> {
 
 
 //Reducing the polynomial into a univariate polynomial
-fn divide_poly_and_sum<F: PrimeField>(poly_eval_values: &Vec<F>) -> Vec<F> {
+fn divide_poly_and_sum<F: PrimeField>(poly_eval_values: &[F]) -> Vec<F> {
     let mut univariate_poly: Vec<F> = Vec::with_capacity(2);
     let middle_point = poly_eval_values.len() / 2;
     let (left, right) = poly_eval_values.split_at(middle_point);
@@ -47,7 +47,7 @@ fn convert_elements_to_bytes<F: PrimeField>(field_element: F) -> Vec<u8> {
 }
 
 impl<F: PrimeField> Prover<F> {
-    pub fn init(poly_eval_values: &Vec<F>) -> Self {
+    pub fn init(poly_eval_values: &[F]) -> Self {
         let poly = MultilinearEvalForm::new(poly_eval_values);
         let transcript = Transcript::<F, Keccak256>::init(Keccak256::new());
         println!("Initial polynomial values: {:?}", poly_eval_values);
@@ -76,10 +76,10 @@ impl<F: PrimeField> Prover<F> {
         for round in 0..self.initial_poly.number_of_variables() {
             println!(
                 "Round {}: Current polynomial values: {:?}",
-                round, current_poly.evaluated_values
+                round, current_poly.evaluated_values()
             );
 
-            let univariate_poly_values = divide_poly_and_sum(&current_poly.evaluated_values);
+            let univariate_poly_values = divide_poly_and_sum(current_poly.evaluated_values());
             println!("Round {}: Univariate poly values: {:?}", round, univariate_poly_values);
 
             let univariate_poly = MultilinearEvalForm::new(&univariate_poly_values);
@@ -91,8 +91,9 @@ impl<F: PrimeField> Prover<F> {
             let random_challenge: F = self.transcript.hash();
             println!("Round {}: Challenge: {:?}", round, random_challenge);
 
-            let evaluated = current_poly.partial_evaluate(round.try_into().unwrap(), random_challenge);
-            current_poly = MultilinearEvalForm::new(&evaluated);
+            // The previous rounds already bound the leading variables, so the
+            // next one to bind is always variable 0 of the folded table
+            current_poly.fix_variable_in_place(random_challenge);
         }
 
         Proof {
@@ -137,8 +138,8 @@ impl<F: PrimeField> Verifier<F> {
 
         for i in 0..proof.round_proof_poly.len() {
             println!("Verifying round {}", i);
-            let eval_at_zero = vec![F::zero()];
-            let eval_at_one = vec![F::one()];
+            let eval_at_zero = [F::zero()];
+            let eval_at_one = [F::one()];
 
             let sum_at_endpoints = proof.round_proof_poly[i].evaluate(&eval_at_zero)
                 + proof.round_proof_poly[i].evaluate(&eval_at_one);
@@ -157,7 +158,7 @@ impl<F: PrimeField> Verifier<F> {
             println!("Round {}: Generated challenge: {:?}", i, challenge);
             challenges.push(challenge);
 
-            current_claim_sum = proof.round_proof_poly[i].evaluate(&vec![challenge]);
+            current_claim_sum = proof.round_proof_poly[i].evaluate(&[challenge]);
             println!("Round {}: New current claim: {:?}", i, current_claim_sum);
         }
 
@@ -181,7 +182,7 @@ mod test {
         let prover = Prover::init(&evaluated_values);
 
         assert_eq!(prover.initial_claimed_sum, Fq::from(11));
-        assert_eq!(prover.initial_poly.evaluated_values, evaluated_values);
+        assert_eq!(prover.initial_poly.evaluated_values(), evaluated_values);
     }
 
     #[test]